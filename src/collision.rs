@@ -0,0 +1,213 @@
+extern crate nalgebra;
+
+use nalgebra::{Vec2, dot, sqnorm};
+
+pub fn circles_overlap(a : Vec2<f32>, a_radius : f32, b : Vec2<f32>, b_radius : f32) -> bool {
+    let reach = a_radius + b_radius;
+    sqnorm(&(a - b)) < reach * reach
+}
+
+// elastic response of two bodies in contact along the normal pointing from the first to
+// the second: the change in velocity of each, and how fast they hit each other. None if
+// they are already moving apart
+pub fn bounce(normal : Vec2<f32>, velocity_a : Vec2<f32>, mass_a : f32, velocity_b : Vec2<f32>, mass_b : f32, restitution : f32) -> Option<(Vec2<f32>, Vec2<f32>, f32)> {
+    let approach = dot(&(velocity_a - velocity_b), &normal);
+    if approach <= 0f32 {
+        return None;
+    }
+
+    let impulse = (1f32 + restitution) * approach / (1f32 / mass_a + 1f32 / mass_b);
+    Some((normal * (-impulse / mass_a), normal * (impulse / mass_b), approach))
+}
+
+pub fn point_in_circle(point : Vec2<f32>, center : Vec2<f32>, radius : f32) -> bool {
+    sqnorm(&(point - center)) < radius * radius
+}
+
+// crossing number test, works for any simple polygon
+pub fn point_in_polygon(point : Vec2<f32>, polygon : &[Vec2<f32>]) -> bool {
+    let mut inside = false;
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let a = polygon[i];
+        let b = polygon[j];
+        if (a.y > point.y) != (b.y > point.y) &&
+           point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+fn closest_point_on_segment(point : Vec2<f32>, a : Vec2<f32>, b : Vec2<f32>) -> Vec2<f32> {
+    let segment = b - a;
+    let length_squared = sqnorm(&segment);
+    if length_squared == 0f32 {
+        return a;
+    }
+    let t = (dot(&(point - a), &segment) / length_squared).max(0f32).min(1f32);
+    a + segment * t
+}
+
+fn distance_to_segment_squared(point : Vec2<f32>, a : Vec2<f32>, b : Vec2<f32>) -> f32 {
+    sqnorm(&(point - closest_point_on_segment(point, a, b)))
+}
+
+pub fn circle_intersects_polygon(center : Vec2<f32>, radius : f32, polygon : &[Vec2<f32>]) -> bool {
+    if point_in_polygon(center, polygon) {
+        return true;
+    }
+
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        if distance_to_segment_squared(center, polygon[j], polygon[i]) < radius * radius {
+            return true;
+        }
+        j = i;
+    }
+    false
+}
+
+fn project(polygon : &[Vec2<f32>], axis : Vec2<f32>) -> (f32, f32) {
+    let mut min = dot(&polygon[0], &axis);
+    let mut max = min;
+    for point in polygon.iter().skip(1) {
+        let projection = dot(point, &axis);
+        min = min.min(projection);
+        max = max.max(projection);
+    }
+    (min, max)
+}
+
+fn has_separating_axis(a : &[Vec2<f32>], b : &[Vec2<f32>]) -> bool {
+    let mut j = a.len() - 1;
+    for i in 0..a.len() {
+        let edge = a[i] - a[j];
+        let axis = Vec2::new(-edge.y, edge.x);
+        let (a_min, a_max) = project(a, axis);
+        let (b_min, b_max) = project(b, axis);
+        if a_max < b_min || b_max < a_min {
+            return true;
+        }
+        j = i;
+    }
+    false
+}
+
+// separating axis test, both polygons must be convex
+pub fn polygons_intersect(a : &[Vec2<f32>], b : &[Vec2<f32>]) -> bool {
+    !has_separating_axis(a, b) && !has_separating_axis(b, a)
+}
+
+// whether every point of the polygon lies on the same side of each of its edges, in
+// either winding order; points along a straight edge are fine
+pub fn is_convex(polygon : &[Vec2<f32>]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut winding = 0f32;
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let edge = polygon[i] - polygon[j];
+        for &point in polygon.iter() {
+            let offset = point - polygon[j];
+            let side = edge.x * offset.y - edge.y * offset.x;
+            if side.abs() < 1e-6 {
+                continue;
+            }
+            if winding == 0f32 {
+                winding = side.signum();
+            }
+            else if side.signum() != winding {
+                return false;
+            }
+        }
+        j = i;
+    }
+    winding != 0f32 // all on one line otherwise
+}
+
+// how far a point is from the edge of a polygon, 0 when inside it
+pub fn distance_to_polygon(point : Vec2<f32>, polygon : &[Vec2<f32>]) -> f32 {
+    if point_in_polygon(point, polygon) {
+        return 0f32;
+    }
+
+    let mut closest = ::std::f32::INFINITY;
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        closest = closest.min(distance_to_segment_squared(point, polygon[j], polygon[i]));
+        j = i;
+    }
+    closest.sqrt()
+}
+
+// the point on the edge of a polygon nearest to the given one, whether that is inside
+// the polygon or not
+pub fn closest_point_on_polygon(point : Vec2<f32>, polygon : &[Vec2<f32>]) -> Vec2<f32> {
+    let mut closest = polygon[0];
+    let mut j = polygon.len() - 1;
+    for i in 0..polygon.len() {
+        let candidate = closest_point_on_segment(point, polygon[j], polygon[i]);
+        if sqnorm(&(point - candidate)) < sqnorm(&(point - closest)) {
+            closest = candidate;
+        }
+        j = i;
+    }
+    closest
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vec2;
+    use super::{bounce, is_convex};
+
+    fn close(a : f32, b : f32) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn bounce_swaps_velocities_of_equal_masses() {
+        let (dva, dvb, approach) = bounce(Vec2::new(1f32, 0f32), Vec2::new(1f32, 0f32), 1f32, Vec2::new(-1f32, 0f32), 1f32, 1f32).unwrap();
+        assert!(close(dva.x, -2f32) && close(dva.y, 0f32));
+        assert!(close(dvb.x,  2f32) && close(dvb.y, 0f32));
+        assert!(close(approach, 2f32));
+    }
+
+    #[test]
+    fn bounce_moves_the_lighter_body_more() {
+        let (dva, dvb, _) = bounce(Vec2::new(0f32, 1f32), Vec2::new(0f32, 1f32), 3f32, Vec2::new(0f32, 0f32), 1f32, 0f32).unwrap();
+        assert!(close(dva.y * 3f32, -dvb.y));
+        assert!(close(1f32 + dva.y, dvb.y)); // no restitution, so they end up moving together
+    }
+
+    #[test]
+    fn bounce_ignores_bodies_moving_apart() {
+        assert!(bounce(Vec2::new(1f32, 0f32), Vec2::new(-1f32, 0f32), 1f32, Vec2::new(1f32, 0f32), 1f32, 1f32).is_none());
+        assert!(bounce(Vec2::new(1f32, 0f32), Vec2::new(0f32, 1f32), 1f32, Vec2::new(0f32, 1f32), 1f32, 1f32).is_none());
+    }
+
+    #[test]
+    fn convex_in_either_winding_order() {
+        let square = [Vec2::new(0f32, 0f32), Vec2::new(1f32, 0f32), Vec2::new(1f32, 1f32), Vec2::new(0f32, 1f32)];
+        assert!(is_convex(&square));
+        let reversed : Vec<Vec2<f32>> = square.iter().rev().cloned().collect();
+        assert!(is_convex(&reversed));
+    }
+
+    #[test]
+    fn not_convex_with_a_dent_or_a_twist() {
+        let dented = [Vec2::new(0f32, 0f32), Vec2::new(1f32, 0f32), Vec2::new(0.3f32, 0.3f32), Vec2::new(0f32, 1f32)];
+        assert!(!is_convex(&dented));
+        let twisted = [Vec2::new(0f32, 0f32), Vec2::new(1f32, 1f32), Vec2::new(1f32, 0f32), Vec2::new(0f32, 1f32)];
+        assert!(!is_convex(&twisted));
+    }
+
+    #[test]
+    fn not_convex_without_an_area() {
+        assert!(!is_convex(&[Vec2::new(0f32, 0f32), Vec2::new(1f32, 0f32)]));
+        assert!(!is_convex(&[Vec2::new(0f32, 0f32), Vec2::new(1f32, 0f32), Vec2::new(2f32, 0f32)]));
+    }
+}
@@ -1,6 +1,153 @@
 extern crate nalgebra;
 
-use nalgebra::Vec2;
+use nalgebra::{Vec2, norm};
+
+// ship outline in ship-local coordinates, pointing along the x axis; ship classes have
+// their own, this one is for the icons and for ships before they are given a class
+pub const SHIP_HULL : [[f32; 2]; 3] = [[-0.05, -0.025], [0.05, 0.0], [-0.05, 0.025]];
+
+pub fn rotate(v : Vec2<f32>, angle : f32) -> Vec2<f32> {
+    let (sin, cos) = (f32::sin(angle), f32::cos(angle));
+    Vec2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
+}
+
+// how far to turn from one angle to get to the other, between -pi and pi
+pub fn angle_difference(from : f32, to : f32) -> f32 {
+    let pi = ::std::f32::consts::PI;
+    let difference = (to - from) % (2f32 * pi);
+    if      difference >  pi { difference - 2f32 * pi }
+    else if difference < -pi { difference + 2f32 * pi }
+    else                     { difference }
+}
+
+// the world is a rectangle centered on the origin; anything leaving it on one side
+// comes back in on the opposite side, unless it is walled in which case it bounces
+pub struct Bounds {
+    pub min    : Vec2<f32>,
+    pub max    : Vec2<f32>,
+    pub walled : bool,
+}
+
+impl Bounds {
+    pub fn new(width : f32, height : f32, walled : bool) -> Bounds {
+        Bounds {
+            min    : Vec2::new(-width / 2f32, -height / 2f32),
+            max    : Vec2::new( width / 2f32,  height / 2f32),
+            walled : walled,
+        }
+    }
+
+    pub fn size(&self) -> Vec2<f32> {
+        self.max - self.min
+    }
+
+    pub fn wrap(&self, position : Vec2<f32>) -> Vec2<f32> {
+        if self.walled {
+            return Vec2::new(position.x.max(self.min.x).min(self.max.x),
+                             position.y.max(self.min.y).min(self.max.y));
+        }
+
+        fn wrap_axis(value : f32, min : f32, max : f32) -> f32 {
+            let length = max - min;
+            let wrapped = (value - min) % length;
+            if wrapped < 0f32 { wrapped + length + min } else { wrapped + min }
+        }
+
+        Vec2::new(wrap_axis(position.x, self.min.x, self.max.x),
+                  wrap_axis(position.y, self.min.y, self.max.y))
+    }
+
+    // the copy of `position` closest to `reference`, possibly outside the bounds
+    pub fn nearest_image(&self, reference : Vec2<f32>, position : Vec2<f32>) -> Vec2<f32> {
+        if self.walled {
+            return position;
+        }
+
+        let size = self.size();
+        let mut delta = position - reference;
+        if delta.x >  size.x / 2f32 { delta.x = delta.x - size.x; }
+        if delta.x < -size.x / 2f32 { delta.x = delta.x + size.x; }
+        if delta.y >  size.y / 2f32 { delta.y = delta.y - size.y; }
+        if delta.y < -size.y / 2f32 { delta.y = delta.y + size.y; }
+        reference + delta
+    }
+
+    // every position an object of the given radius needs to be drawn at to show up
+    // on both sides of an edge it is straddling
+    pub fn images(&self, position : Vec2<f32>, radius : f32) -> Vec<Vec2<f32>> {
+        if self.walled {
+            return vec![position];
+        }
+
+        let size = self.size();
+        let mut images = Vec::new();
+        for &dx in [-size.x, 0f32, size.x].iter() {
+            for &dy in [-size.y, 0f32, size.y].iter() {
+                let image = position + Vec2::new(dx, dy);
+                if image.x + radius > self.min.x && image.x - radius < self.max.x &&
+                   image.y + radius > self.min.y && image.y - radius < self.max.y {
+                    images.push(image);
+                }
+            }
+        }
+        images
+    }
+
+    // brings a position that went past an edge back inside: wrapped around, or mirrored
+    // off the wall. also returns a factor per axis for flipping velocities to match
+    pub fn contain(&self, position : Vec2<f32>) -> (Vec2<f32>, Vec2<f32>) {
+        if !self.walled {
+            return (self.wrap(position), Vec2::new(1f32, 1f32));
+        }
+
+        fn bounce_axis(value : f32, min : f32, max : f32) -> (f32, f32) {
+            if value < min      { ((2f32 * min - value).min(max), -1f32) }
+            else if value > max { ((2f32 * max - value).max(min), -1f32) }
+            else                { (value, 1f32) }
+        }
+
+        let (x, flip_x) = bounce_axis(position.x, self.min.x, self.max.x);
+        let (y, flip_y) = bounce_axis(position.y, self.min.y, self.max.y);
+        (Vec2::new(x, y), Vec2::new(flip_x, flip_y))
+    }
+}
+
+// componentwise product, for applying the flips from Bounds::contain
+pub fn scale(v : Vec2<f32>, factors : Vec2<f32>) -> Vec2<f32> {
+    Vec2::new(v.x * factors.x, v.y * factors.y)
+}
+
+// a docking station, where ships fill up their fuel tank
+pub struct Station {
+    pub position : Vec2<f32>,
+    pub radius   : f32,
+}
+
+impl Station {
+    pub fn new(position : Vec2<f32>, radius : f32) -> Station {
+        Station {
+            position : position,
+            radius   : radius,
+        }
+    }
+}
+
+// static convex polygon in the arena that nothing can pass through
+pub struct Obstacle {
+    pub points : Vec<Vec2<f32>>,
+    pub center : Vec2<f32>,
+}
+
+impl Obstacle {
+    pub fn new(points : Vec<Vec2<f32>>) -> Obstacle {
+        let sum = points.iter().fold(Vec2::new(0f32, 0f32), |sum, &point| sum + point);
+        let center = sum * (1f32 / points.len() as f32);
+        Obstacle {
+            points : points,
+            center : center,
+        }
+    }
+}
 
 pub enum Integrator {
     ForwardEuler,
@@ -14,49 +161,605 @@ pub struct Ship {
     pub velocity            : Vec2<f32>,
     pub prev_position       : Vec2<f32>,
     pub prev_rotation       : f32,
+    pub invulnerable        : u64,
+    pub shield_active       : bool,
+    pub energy              : f32,
+    pub hitbox              : Vec<Vec2<f32>>, // in ship-local coordinates
+    pub mass                : f32,
+    pub fuel                : f32,
+    pub hull_points         : f32, // left, out of max_hull_points
+    pub max_hull_points     : f32,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Score {
+    pub points : u32,
+    pub kills  : u32,
+    pub deaths : u32,
+    pub lives  : u32,
+}
+
+impl Score {
+    pub fn new(lives : u32) -> Score {
+        Score {
+            points : 0,
+            kills  : 0,
+            deaths : 0,
+            lives  : lives,
+        }
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.lives == 0
+    }
+}
+
+// one color per team, for both the ships and their scoreboard lines
+pub const TEAM_COLORS : [[f32; 3]; 4] = [[0.4, 0.7, 1.0], [1.0, 0.45, 0.35], [0.5, 1.0, 0.4], [1.0, 0.9, 0.3]];
+
+pub fn team_color(team : u8) -> [f32; 3] {
+    TEAM_COLORS[team as usize % TEAM_COLORS.len()]
+}
+
+// adds up the scores of everyone on the same team, from (client id, team, score) entries
+pub fn team_scores(scores : &[(u8, u8, Score)]) -> Vec<(u8, Score)> {
+    let mut totals : Vec<(u8, Score)> = Vec::new();
+    for &(_, team, ref score) in scores.iter() {
+        let index = match totals.iter().position(|&(t, _)| t == team) {
+            Some(index) => index,
+            None        => { totals.push((team, Score::new(0))); totals.len() - 1 },
+        };
+        let total = &mut totals[index].1;
+        total.points += score.points;
+        total.kills  += score.kills;
+        total.deaths += score.deaths;
+        total.lives  += score.lives;
+    }
+    totals.sort_by(|a, b| a.0.cmp(&b.0));
+    totals
+}
+
+// where the server is in the cycle of matches, only InProgress is actually played
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MatchPhase {
+    Waiting,    // for enough players to join
+    ReadyCheck, // until everyone has pressed ready
+    Countdown,
+    InProgress,
+    Results,
+}
+
+impl MatchPhase {
+    pub fn to_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_u8(value : u8) -> MatchPhase {
+        match value {
+            1 => MatchPhase::ReadyCheck,
+            2 => MatchPhase::Countdown,
+            3 => MatchPhase::InProgress,
+            4 => MatchPhase::Results,
+            _ => MatchPhase::Waiting,
+        }
+    }
 }
 
 pub struct Pilot {
-    pub ship             : Option<Ship>,
-    pub left_is_pressed  : bool,
-    pub right_is_pressed : bool,
-    pub up_is_pressed    : bool,
-    pub integrator       : Integrator,
+    pub ship                    : Option<Ship>,
+    pub left_is_pressed         : bool,
+    pub right_is_pressed        : bool,
+    pub up_is_pressed           : bool,
+    pub down_is_pressed         : bool,
+    pub brake_is_pressed        : bool,
+    pub strafe_left_is_pressed  : bool,
+    pub strafe_right_is_pressed : bool,
+    pub fire_is_pressed         : bool,
+    pub fire_cooldown           : u64,
+    pub respawn_is_pressed      : bool,
+    pub respawn_timer           : u64,
+    pub hyperspace_is_pressed   : bool,
+    pub hyperspace_cooldown     : u64,
+    pub shield_is_pressed       : bool,
+    pub rapid_fire_timer        : u64,
+    pub spread_shot_timer       : u64,
+    pub mine_is_pressed         : bool,
+    pub mine_cooldown           : u64,
+    pub missile_is_pressed      : bool,
+    pub missile_cooldown        : u64,
+    pub class                   : u8,             // index into the ship classes in the settings
+    pub hitbox                  : Vec<Vec2<f32>>, // of the class, given to every ship it spawns
+    pub mass                    : f32,            // of the class as well
+    pub hull_points             : f32,
+    pub integrator              : Integrator,
 }
 
 impl Pilot {
     pub fn new(integrator : Integrator) -> Self {
         Pilot {
-            ship             : None,
-            left_is_pressed  : false,
-            right_is_pressed : false,
-            up_is_pressed    : false,
-            integrator       : integrator,
+            ship                    : None,
+            left_is_pressed         : false,
+            right_is_pressed        : false,
+            up_is_pressed           : false,
+            down_is_pressed         : false,
+            brake_is_pressed        : false,
+            strafe_left_is_pressed  : false,
+            strafe_right_is_pressed : false,
+            fire_is_pressed         : false,
+            fire_cooldown           : 0,
+            respawn_is_pressed      : false,
+            respawn_timer           : 0,
+            hyperspace_is_pressed   : false,
+            hyperspace_cooldown     : 0,
+            shield_is_pressed       : false,
+            rapid_fire_timer        : 0,
+            spread_shot_timer       : 0,
+            mine_is_pressed         : false,
+            mine_cooldown           : 0,
+            missile_is_pressed      : false,
+            missile_cooldown        : 0,
+            class                   : 0,
+            hitbox                  : SHIP_HULL.iter().map(|point| Vec2::new(point[0], point[1])).collect(),
+            mass                    : 1f32,
+            hull_points             : 1f32,
+            integrator              : integrator,
         }
     }
 
-    pub fn spawn(&mut self) -> Result<(), ()> {
+    pub fn spawn(&mut self, position : Vec2<f32>, invulnerability : u64) -> Result<(), ()> {
         match self.ship {
-            None => { self.ship = Some(Ship::new()); Ok(()) },
+            None => {
+                let mut ship = Ship::new(position);
+                ship.invulnerable = invulnerability;
+                ship.hitbox = self.hitbox.clone();
+                ship.mass = self.mass;
+                ship.hull_points = self.hull_points;
+                ship.max_hull_points = self.hull_points;
+                self.ship = Some(ship);
+                Ok(())
+            },
             _    => Err(()),
         }
     }
 
+    pub fn destroy(&mut self, respawn_delay : u64) {
+        self.ship = None;
+        self.respawn_timer = respawn_delay;
+        self.rapid_fire_timer = 0;
+        self.spread_shot_timer = 0;
+    }
+
+    pub fn can_respawn(&self) -> bool {
+        self.ship.is_none() && self.respawn_timer == 0
+    }
+
     pub fn ship(&self) -> &Option<Ship> {
         &self.ship
     }
 }
 
 impl Ship {
-    pub fn new() -> Ship {
+    pub fn new(position : Vec2<f32>) -> Ship {
         Ship {
             rotation            : 0f32,
             rotational_velocity : 0f32,
-            position            : Vec2::new(0.3f32, 0.1f32),
+            position            : position,
             velocity            : Vec2::new(0.0f32, 0.0f32),
-            prev_position       : Vec2::new(0.3f32, 0.1f32),
+            prev_position       : position,
             prev_rotation       : 0f32,
+            invulnerable        : 0,
+            shield_active       : false,
+            energy              : 1f32,
+            hitbox              : SHIP_HULL.iter().map(|point| Vec2::new(point[0], point[1])).collect(),
+            mass                : 1f32,
+            fuel                : 1f32,
+            hull_points         : 1f32,
+            max_hull_points     : 1f32,
+        }
+    }
+
+    // moves the ship back inside the bounds, shifting the previous position along with it
+    // so that velocities derived from it don't see the jump, or flip when bouncing off a wall
+    pub fn wrap(&mut self, bounds : &Bounds) {
+        let (contained, flip) = bounds.contain(self.position);
+        self.prev_position = contained + scale(self.prev_position - self.position, flip);
+        self.velocity = scale(self.velocity, flip);
+        self.position = contained;
+    }
+
+    // jumps to a new position at a standstill, resetting the previous position too so
+    // that neither integrator mistakes the jump for velocity
+    pub fn teleport(&mut self, position : Vec2<f32>) {
+        self.position = position;
+        self.prev_position = position;
+        self.velocity = Vec2::new(0f32, 0f32);
+    }
+
+    // moves the ship without changing its velocity, under either integrator
+    pub fn shift(&mut self, offset : Vec2<f32>) {
+        self.position = self.position + offset;
+        self.prev_position = self.prev_position + offset;
+    }
+
+    // what the ship moved over the last step, which is its velocity for either integrator
+    pub fn displacement(&self) -> Vec2<f32> {
+        self.position - self.prev_position
+    }
+
+    // changes the velocity right away; Verlet only knows velocity as the difference to the
+    // previous position, so that has to move the opposite way
+    pub fn apply_impulse(&mut self, delta_velocity : Vec2<f32>) {
+        self.velocity = self.velocity + delta_velocity;
+        self.prev_position = self.prev_position - delta_velocity;
+    }
+
+    // the shield stays up for as long as it is held and there is energy left for it,
+    // and recharges while it is down
+    pub fn update_shield(&mut self, shield_is_pressed : bool, drain : f32, recharge : f32) {
+        self.shield_active = shield_is_pressed && self.energy > 0f32;
+        if self.shield_active {
+            self.energy = (self.energy - drain).max(0f32);
+        }
+        else {
+            self.energy = (self.energy + recharge).min(1f32);
         }
     }
+
+    pub fn is_invulnerable(&self) -> bool {
+        self.invulnerable > 0
+    }
+
+    // how much of the hull is left, from 0 to 1
+    pub fn integrity(&self) -> f32 {
+        if self.max_hull_points > 0f32 {
+            (self.hull_points / self.max_hull_points).max(0f32).min(1f32)
+        }
+        else {
+            0f32
+        }
+    }
+
+    pub fn hull(&self) -> Vec<Vec2<f32>> {
+        self.hitbox.iter()
+            .map(|&point| self.position + rotate(point, self.rotation))
+            .collect()
+    }
 }
 
+
+pub const ASTEROID_SMALL  : u8 = 1;
+pub const ASTEROID_MEDIUM : u8 = 2;
+pub const ASTEROID_LARGE  : u8 = 3;
+
+pub struct Asteroid {
+    pub id                  : u16,
+    pub size                : u8,
+    pub radius              : f32,
+    pub rotation            : f32,
+    pub rotational_velocity : f32,
+    pub position            : Vec2<f32>,
+    pub velocity            : Vec2<f32>,
+}
+
+impl Asteroid {
+    pub fn new(id : u16, size : u8, position : Vec2<f32>, velocity : Vec2<f32>, rotational_velocity : f32) -> Asteroid {
+        Asteroid {
+            id                  : id,
+            size                : size,
+            radius              : Asteroid::radius_for_size(size),
+            rotation            : 0f32,
+            rotational_velocity : rotational_velocity,
+            position            : position,
+            velocity            : velocity,
+        }
+    }
+
+    pub fn radius_for_size(size : u8) -> f32 {
+        0.05f32 * size as f32
+    }
+
+    pub fn step(&mut self, bounds : &Bounds) {
+        let (position, flip) = bounds.contain(self.position + self.velocity);
+        self.position = position;
+        self.velocity = scale(self.velocity, flip);
+        self.rotation += self.rotational_velocity;
+    }
+
+    // breaks the asteroid into two smaller ones flying apart, or None if it is already
+    // the smallest size and should just disappear
+    pub fn split(&self, first_id : u16, second_id : u16) -> Option<(Asteroid, Asteroid)> {
+        if self.size <= ASTEROID_SMALL {
+            return None;
+        }
+
+        let size = self.size - 1;
+        let spread = ::std::f32::consts::PI / 4f32;
+        let velocity = self.velocity * 1.5f32;
+        let offset = rotate(Vec2::new(Asteroid::radius_for_size(size), 0f32), self.rotation);
+
+        Some((Asteroid::new(first_id,  size, self.position + offset, rotate(velocity,  spread),  self.rotational_velocity * 1.5f32),
+              Asteroid::new(second_id, size, self.position - offset, rotate(velocity, -spread), -self.rotational_velocity * 1.5f32)))
+    }
+}
+
+pub struct Bullet {
+    pub owner    : Option<u8>, // None for bullets fired by saucers
+    pub position : Vec2<f32>,
+    pub velocity : Vec2<f32>,
+    pub lifetime : u64,
+}
+
+impl Bullet {
+    pub fn new(owner : Option<u8>, position : Vec2<f32>, velocity : Vec2<f32>, lifetime : u64) -> Bullet {
+        Bullet {
+            owner    : owner,
+            position : position,
+            velocity : velocity,
+            lifetime : lifetime,
+        }
+    }
+
+    pub fn step(&mut self, bounds : &Bounds) {
+        let (position, flip) = bounds.contain(self.position + self.velocity);
+        self.position = position;
+        self.velocity = scale(self.velocity, flip);
+        self.lifetime = self.lifetime.saturating_sub(1);
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.lifetime == 0
+    }
+}
+
+// sits where it was dropped until something hostile comes close, then takes everything
+// around it along
+pub struct Mine {
+    pub owner     : u8,
+    pub position  : Vec2<f32>,
+    pub arm_timer : u64,
+    pub lifetime  : u64,
+}
+
+impl Mine {
+    pub fn new(owner : u8, position : Vec2<f32>, arm_delay : u64, lifetime : u64) -> Mine {
+        Mine {
+            owner     : owner,
+            position  : position,
+            arm_timer : arm_delay,
+            lifetime  : lifetime,
+        }
+    }
+
+    pub fn step(&mut self) {
+        self.arm_timer = self.arm_timer.saturating_sub(1);
+        self.lifetime = self.lifetime.saturating_sub(1);
+    }
+
+    pub fn is_armed(&self) -> bool {
+        self.arm_timer == 0
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.lifetime == 0
+    }
+}
+
+// steers towards its target for as long as its fuel lasts, then drifts on until it
+// burns out
+pub struct Missile {
+    pub owner    : u8,
+    pub target   : Option<u8>, // the ship it locked on to when it was launched
+    pub position : Vec2<f32>,
+    pub velocity : Vec2<f32>,
+    pub rotation : f32,
+    pub fuel     : u64,
+    pub lifetime : u64,
+}
+
+impl Missile {
+    pub fn new(owner : u8, target : Option<u8>, position : Vec2<f32>, velocity : Vec2<f32>, rotation : f32, fuel : u64, lifetime : u64) -> Missile {
+        Missile {
+            owner    : owner,
+            target   : target,
+            position : position,
+            velocity : velocity,
+            rotation : rotation,
+            fuel     : fuel,
+            lifetime : lifetime,
+        }
+    }
+
+    // turns at most turn_rate towards the target, then thrusts along its heading the
+    // way a ship does
+    pub fn step(&mut self, target : Option<Vec2<f32>>, turn_rate : f32, acceleration : f32, drag : f32, bounds : &Bounds) {
+        if self.fuel > 0 {
+            if let Some(target) = target {
+                let delta = bounds.nearest_image(self.position, target) - self.position;
+                let turn = angle_difference(self.rotation, f32::atan2(delta.y, delta.x));
+                self.rotation += turn.max(-turn_rate).min(turn_rate);
+            }
+
+            let direction = Vec2::new(f32::cos(self.rotation), f32::sin(self.rotation));
+            self.velocity = (self.velocity + direction * acceleration) * (1f32 - drag);
+            self.fuel -= 1;
+        }
+
+        let (position, flip) = bounds.contain(self.position + self.velocity);
+        if flip.x < 0f32 || flip.y < 0f32 {
+            let heading = scale(Vec2::new(f32::cos(self.rotation), f32::sin(self.rotation)), flip);
+            self.rotation = f32::atan2(heading.y, heading.x);
+        }
+        self.position = position;
+        self.velocity = scale(self.velocity, flip);
+        self.lifetime = self.lifetime.saturating_sub(1);
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.lifetime == 0
+    }
+}
+
+pub const POWER_UP_RAPID_FIRE      : u8 = 0;
+pub const POWER_UP_SPREAD_SHOT     : u8 = 1;
+pub const POWER_UP_EXTRA_LIFE      : u8 = 2;
+pub const POWER_UP_SHIELD_RECHARGE : u8 = 3;
+pub const POWER_UP_FUEL            : u8 = 4; // only handed out when fuel is limited
+
+pub const POWER_UP_RADIUS : f32 = 0.04;
+
+pub struct PowerUp {
+    pub id       : u16,
+    pub kind     : u8,
+    pub position : Vec2<f32>,
+    pub lifetime : u64,
+}
+
+impl PowerUp {
+    pub fn new(id : u16, kind : u8, position : Vec2<f32>, lifetime : u64) -> PowerUp {
+        PowerUp {
+            id       : id,
+            kind     : kind,
+            position : position,
+            lifetime : lifetime,
+        }
+    }
+
+    pub fn step(&mut self) {
+        self.lifetime = self.lifetime.saturating_sub(1);
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.lifetime == 0
+    }
+}
+
+// a planet or other heavy body; pulls everything towards it and destroys what touches it.
+// it either sits still or circles around its center
+pub struct GravityWell {
+    pub center       : Vec2<f32>,
+    pub orbit_radius : f32,
+    pub orbit_speed  : f32, // radians per ms
+    pub angle        : f32,
+    pub position     : Vec2<f32>,
+    pub velocity     : Vec2<f32>,
+    pub radius       : f32,
+    pub strength     : f32, // acceleration at a distance of 1
+}
+
+impl GravityWell {
+    pub fn new(center : Vec2<f32>, orbit_radius : f32, orbit_speed : f32, radius : f32, strength : f32) -> GravityWell {
+        GravityWell {
+            center       : center,
+            orbit_radius : orbit_radius,
+            orbit_speed  : orbit_speed,
+            angle        : 0f32,
+            position     : center + Vec2::new(orbit_radius, 0f32),
+            velocity     : Vec2::new(0f32, orbit_radius * orbit_speed),
+            radius       : radius,
+            strength     : strength,
+        }
+    }
+
+    pub fn step(&mut self, bounds : &Bounds) {
+        self.angle += self.orbit_speed;
+        let position = bounds.wrap(self.center + Vec2::new(f32::cos(self.angle), f32::sin(self.angle)) * self.orbit_radius);
+        self.velocity = bounds.nearest_image(self.position, position) - self.position;
+        self.position = position;
+    }
+
+    // inverse square pull on something at the given position, capped at the surface so
+    // nothing gets flung out from right next to the center
+    pub fn pull(&self, position : Vec2<f32>, bounds : &Bounds) -> Vec2<f32> {
+        let delta = bounds.nearest_image(position, self.position) - position;
+        let distance = norm(&delta).max(self.radius);
+        delta * (self.strength / (distance * distance * distance))
+    }
+}
+
+pub fn gravity(wells : &[GravityWell], position : Vec2<f32>, bounds : &Bounds) -> Vec2<f32> {
+    wells.iter().fold(Vec2::new(0f32, 0f32), |total, well| total + well.pull(position, bounds))
+}
+
+pub struct Saucer {
+    pub id            : u16,
+    pub small         : bool,
+    pub radius        : f32,
+    pub position      : Vec2<f32>,
+    pub velocity      : Vec2<f32>,
+    pub fire_cooldown : u64,
+    pub course_timer  : u64, // time left until the next change of course
+    pub distance_left : f32, // how far it flies before leaving the arena
+}
+
+impl Saucer {
+    pub fn new(id : u16, small : bool, radius : f32, position : Vec2<f32>, velocity : Vec2<f32>, distance : f32) -> Saucer {
+        Saucer {
+            id            : id,
+            small         : small,
+            radius        : radius,
+            position      : position,
+            velocity      : velocity,
+            fire_cooldown : 0,
+            course_timer  : 0,
+            distance_left : distance,
+        }
+    }
+
+    pub fn step(&mut self, bounds : &Bounds) {
+        self.position = bounds.wrap(self.position + self.velocity);
+        self.distance_left -= self.velocity.x.abs();
+        self.fire_cooldown = self.fire_cooldown.saturating_sub(1);
+        self.course_timer = self.course_timer.saturating_sub(1);
+    }
+
+    pub fn has_left(&self) -> bool {
+        self.distance_left <= 0f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nalgebra::Vec2;
+    use super::Bounds;
+
+    fn close(a : Vec2<f32>, b : Vec2<f32>) -> bool {
+        (a.x - b.x).abs() < 1e-6 && (a.y - b.y).abs() < 1e-6
+    }
+
+    #[test]
+    fn contain_wraps_around_without_walls() {
+        let bounds = Bounds::new(2f32, 2f32, false);
+        let (position, flip) = bounds.contain(Vec2::new(1.2f32, -1.5f32));
+        assert!(close(position, Vec2::new(-0.8f32, 0.5f32)));
+        assert!(close(flip, Vec2::new(1f32, 1f32)));
+    }
+
+    #[test]
+    fn contain_mirrors_off_the_walls() {
+        let bounds = Bounds::new(2f32, 2f32, true);
+        let (position, flip) = bounds.contain(Vec2::new(1.2f32, 0.5f32));
+        assert!(close(position, Vec2::new(0.8f32, 0.5f32)));
+        assert!(close(flip, Vec2::new(-1f32, 1f32)));
+
+        let (position, flip) = bounds.contain(Vec2::new(-1.5f32, -1.1f32));
+        assert!(close(position, Vec2::new(-0.5f32, -0.9f32)));
+        assert!(close(flip, Vec2::new(-1f32, -1f32)));
+    }
+
+    #[test]
+    fn contain_keeps_a_mirrored_position_inside() {
+        let bounds = Bounds::new(2f32, 2f32, true);
+        let (position, _) = bounds.contain(Vec2::new(3.5f32, 0f32));
+        assert!(close(position, Vec2::new(-1f32, 0f32)));
+    }
+
+    #[test]
+    fn contain_leaves_positions_inside_alone() {
+        for &walled in [false, true].iter() {
+            let bounds = Bounds::new(2f32, 2f32, walled);
+            let (position, flip) = bounds.contain(Vec2::new(0.3f32, -0.7f32));
+            assert!(close(position, Vec2::new(0.3f32, -0.7f32)));
+            assert!(close(flip, Vec2::new(1f32, 1f32)));
+        }
+    }
+}
@@ -0,0 +1,189 @@
+extern crate rustc_serialize;
+
+use common::{Score, team_scores};
+use settings::Settings;
+
+// picked in settings.json when the server starts, decides who can hurt whom, what
+// scores points and when a round is over
+#[derive(RustcDecodable, RustcEncodable, Clone, Copy, PartialEq, Debug)]
+pub enum GameMode {
+    Coop,           // everyone against the asteroids, ships can't hurt each other
+    FreeForAll,     // every ship for itself
+    TeamDeathmatch, // two teams, respawning without limit
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Outcome {
+    Winners(Vec<u8>), // ids of the winning clients
+    Defeat,           // everyone lost
+}
+
+impl GameMode {
+    // everyone is on team 0 unless the mode is played in teams
+    pub fn team_count(&self) -> u8 {
+        match *self {
+            GameMode::TeamDeathmatch => 2,
+            _                        => 1,
+        }
+    }
+
+    // whether a bullet or collision from one client's ship can destroy the other's
+    pub fn is_hostile(&self, attacker : u8, attacker_team : u8, victim : u8, victim_team : u8) -> bool {
+        match *self {
+            GameMode::Coop           => false,
+            GameMode::FreeForAll     => attacker != victim,
+            GameMode::TeamDeathmatch => attacker_team != victim_team,
+        }
+    }
+
+    pub fn ship_points(&self, settings : &Settings) -> u32 {
+        match *self {
+            GameMode::Coop => 0,
+            _              => settings.ship_points,
+        }
+    }
+
+    pub fn scores_asteroids(&self) -> bool {
+        *self != GameMode::TeamDeathmatch
+    }
+
+    // ships only run out of lives when there is no other way for a round to end
+    pub fn uses_lives(&self) -> bool {
+        *self != GameMode::TeamDeathmatch
+    }
+
+    // who wins when the time runs out before anyone reached the goal
+    pub fn leaders(&self, scores : &[(u8, u8, Score)]) -> Outcome {
+        match *self {
+            GameMode::Coop => Outcome::Defeat,
+            GameMode::FreeForAll => {
+                let best = scores.iter().fold(0, |best, &(_, _, ref score)| ::std::cmp::max(best, score.points));
+                Outcome::Winners(scores.iter().filter(|&&(_, _, ref score)| score.points == best).map(|&(id, _, _)| id).collect())
+            },
+            GameMode::TeamDeathmatch => {
+                let teams = team_scores(scores);
+                let best = teams.iter().fold(0, |best, &(_, ref score)| ::std::cmp::max(best, score.kills));
+                let best_teams : Vec<u8> = teams.iter().filter(|&&(_, ref score)| score.kills == best).map(|&(team, _)| team).collect();
+                Outcome::Winners(scores.iter().filter(|&&(_, team, _)| best_teams.contains(&team)).map(|&(id, _, _)| id).collect())
+            },
+        }
+    }
+
+    // scores are (client id, team, score); a kill limit of 0 means there is none
+    pub fn outcome(&self, scores : &[(u8, u8, Score)], wave : u32, wave_cleared : bool, kill_limit : u32, coop_waves_to_win : u32) -> Option<Outcome> {
+        if scores.is_empty() {
+            return None;
+        }
+
+        let everyone : Vec<u8> = scores.iter().map(|&(id, _, _)| id).collect();
+        let survivors : Vec<u8> = scores.iter().filter(|&&(_, _, ref score)| !score.is_game_over()).map(|&(id, _, _)| id).collect();
+
+        match *self {
+            GameMode::Coop => {
+                if survivors.is_empty() {
+                    Some(Outcome::Defeat)
+                }
+                else if wave >= coop_waves_to_win && wave_cleared {
+                    Some(Outcome::Winners(everyone))
+                }
+                else {
+                    None
+                }
+            },
+            GameMode::FreeForAll => {
+                if let Some(&(id, _, _)) = scores.iter().find(|&&(_, _, ref score)| kill_limit > 0 && score.kills >= kill_limit) {
+                    Some(Outcome::Winners(vec![id]))
+                }
+                else if survivors.is_empty() {
+                    Some(Outcome::Defeat)
+                }
+                else if survivors.len() == 1 && scores.len() > 1 {
+                    Some(Outcome::Winners(survivors))
+                }
+                else {
+                    None
+                }
+            },
+            GameMode::TeamDeathmatch => {
+                match team_scores(scores).into_iter().find(|&(_, ref score)| kill_limit > 0 && score.kills >= kill_limit) {
+                    Some((team, _)) => Some(Outcome::Winners(scores.iter().filter(|&&(_, t, _)| t == team).map(|&(id, _, _)| id).collect())),
+                    None            => None,
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Score;
+    use super::{GameMode, Outcome};
+
+    fn score(points : u32, kills : u32, lives : u32) -> Score {
+        let mut score = Score::new(lives);
+        score.points = points;
+        score.kills = kills;
+        score
+    }
+
+    #[test]
+    fn leaders_share_a_tie() {
+        let scores = vec![(0, 0, score(10, 0, 1)), (1, 0, score(30, 0, 1)), (2, 0, score(30, 0, 1))];
+        assert_eq!(GameMode::FreeForAll.leaders(&scores), Outcome::Winners(vec![1, 2]));
+
+        let scores = vec![(0, 0, score(0, 2, 1)), (1, 1, score(0, 1, 1)), (2, 1, score(0, 1, 1))];
+        assert_eq!(GameMode::TeamDeathmatch.leaders(&scores), Outcome::Winners(vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn leaders_of_the_team_with_the_most_kills() {
+        let scores = vec![(0, 0, score(50, 1, 1)), (1, 1, score(0, 2, 1)), (2, 1, score(0, 0, 1))];
+        assert_eq!(GameMode::TeamDeathmatch.leaders(&scores), Outcome::Winners(vec![1, 2]));
+    }
+
+    #[test]
+    fn leaders_of_an_empty_roster() {
+        assert_eq!(GameMode::Coop.leaders(&[]), Outcome::Defeat);
+        assert_eq!(GameMode::FreeForAll.leaders(&[]), Outcome::Winners(vec![]));
+        assert_eq!(GameMode::TeamDeathmatch.leaders(&[]), Outcome::Winners(vec![]));
+    }
+
+    #[test]
+    fn no_outcome_for_an_empty_roster() {
+        assert_eq!(GameMode::Coop.outcome(&[], 3, true, 5, 3), None);
+        assert_eq!(GameMode::FreeForAll.outcome(&[], 0, false, 5, 3), None);
+        assert_eq!(GameMode::TeamDeathmatch.outcome(&[], 0, false, 5, 3), None);
+    }
+
+    #[test]
+    fn coop_is_won_by_clearing_the_last_wave() {
+        let scores = vec![(0, 0, score(0, 0, 1)), (1, 0, score(0, 0, 0))];
+        assert_eq!(GameMode::Coop.outcome(&scores, 3, false, 5, 3), None);
+        assert_eq!(GameMode::Coop.outcome(&scores, 3, true, 5, 3), Some(Outcome::Winners(vec![0, 1])));
+
+        let scores = vec![(0, 0, score(0, 0, 0)), (1, 0, score(0, 0, 0))];
+        assert_eq!(GameMode::Coop.outcome(&scores, 3, true, 5, 3), Some(Outcome::Defeat));
+    }
+
+    #[test]
+    fn free_for_all_ends_at_the_kill_limit_or_the_last_survivor() {
+        let scores = vec![(0, 0, score(0, 4, 1)), (1, 0, score(0, 5, 1))];
+        assert_eq!(GameMode::FreeForAll.outcome(&scores, 0, false, 5, 3), Some(Outcome::Winners(vec![1])));
+
+        let scores = vec![(0, 0, score(0, 0, 0)), (1, 0, score(0, 0, 2))];
+        assert_eq!(GameMode::FreeForAll.outcome(&scores, 0, false, 5, 3), Some(Outcome::Winners(vec![1])));
+
+        // a lone player keeps going
+        let scores = vec![(0, 0, score(0, 0, 2))];
+        assert_eq!(GameMode::FreeForAll.outcome(&scores, 0, false, 5, 3), None);
+    }
+
+    #[test]
+    fn team_deathmatch_ends_when_a_team_reaches_the_kill_limit() {
+        let scores = vec![(0, 0, score(0, 4, 0)), (1, 1, score(0, 3, 0)), (2, 1, score(0, 2, 0))];
+        assert_eq!(GameMode::TeamDeathmatch.outcome(&scores, 0, false, 5, 3), Some(Outcome::Winners(vec![1, 2])));
+
+        // without a limit it only ends on time
+        assert_eq!(GameMode::TeamDeathmatch.outcome(&scores, 0, false, 0, 3), None);
+    }
+}
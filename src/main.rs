@@ -5,11 +5,15 @@ extern crate nalgebra;
 extern crate rustc_serialize;
 extern crate capnp;
 extern crate mio;
+extern crate rand;
 
 mod network_capnp;
 mod settings;
 mod server;
 mod common;
+mod collision;
+mod game_mode;
+mod map;
 
 use glium::Surface;
 use glium::glutin;
@@ -29,47 +33,100 @@ struct LocalPlayer {
     pub left_key         : VirtualKeyCode,
     pub right_key        : VirtualKeyCode,
     pub up_key           : VirtualKeyCode,
+    pub down_key         : VirtualKeyCode,
+    pub brake_key        : VirtualKeyCode,
+    pub strafe_left_key  : VirtualKeyCode,
+    pub strafe_right_key : VirtualKeyCode,
+    pub fire_key         : VirtualKeyCode,
+    pub respawn_key      : VirtualKeyCode,
+    pub hyperspace_key   : VirtualKeyCode,
+    pub shield_key       : VirtualKeyCode,
+    pub mine_key         : VirtualKeyCode,
+    pub missile_key      : VirtualKeyCode,
 }
 
 impl LocalPlayer {
-    fn new(left_key : VirtualKeyCode, right_key : VirtualKeyCode, up_key : VirtualKeyCode, integrator : Integrator) -> Self {
+    fn new(left_key : VirtualKeyCode, right_key : VirtualKeyCode, up_key : VirtualKeyCode, down_key : VirtualKeyCode, brake_key : VirtualKeyCode, strafe_left_key : VirtualKeyCode, strafe_right_key : VirtualKeyCode, fire_key : VirtualKeyCode, respawn_key : VirtualKeyCode, hyperspace_key : VirtualKeyCode, shield_key : VirtualKeyCode, mine_key : VirtualKeyCode, missile_key : VirtualKeyCode, integrator : Integrator) -> Self {
         LocalPlayer {
             pilot            : Pilot::new(integrator),
             left_key         : left_key,
             right_key        : right_key,
             up_key           : up_key,
+            down_key         : down_key,
+            brake_key        : brake_key,
+            strafe_left_key  : strafe_left_key,
+            strafe_right_key : strafe_right_key,
+            fire_key         : fire_key,
+            respawn_key      : respawn_key,
+            hyperspace_key   : hyperspace_key,
+            shield_key       : shield_key,
+            mine_key         : mine_key,
+            missile_key      : missile_key,
         }
     }
 
     fn spawn(&mut self) -> Result<(), ()> {
-        self.pilot.spawn()
+        self.pilot.spawn(Vec2::new(0f32, 0f32), 0)
     }
 
     fn on_key(&mut self, key: VirtualKeyCode, pressed: bool) -> bool {
-        if      key == self.left_key  { self.pilot.left_is_pressed  = pressed; true }
-        else if key == self.right_key { self.pilot.right_is_pressed = pressed; true }
-        else if key == self.up_key    { self.pilot.up_is_pressed    = pressed; true }
+        if      key == self.left_key         { self.pilot.left_is_pressed         = pressed; true }
+        else if key == self.right_key        { self.pilot.right_is_pressed        = pressed; true }
+        else if key == self.up_key           { self.pilot.up_is_pressed           = pressed; true }
+        else if key == self.down_key         { self.pilot.down_is_pressed         = pressed; true }
+        else if key == self.brake_key        { self.pilot.brake_is_pressed        = pressed; true }
+        else if key == self.strafe_left_key  { self.pilot.strafe_left_is_pressed  = pressed; true }
+        else if key == self.strafe_right_key { self.pilot.strafe_right_is_pressed = pressed; true }
+        else if key == self.fire_key         { self.pilot.fire_is_pressed         = pressed; true }
+        else if key == self.respawn_key      { self.pilot.respawn_is_pressed      = pressed; true }
+        else if key == self.hyperspace_key   { self.pilot.hyperspace_is_pressed   = pressed; true }
+        else if key == self.shield_key       { self.pilot.shield_is_pressed       = pressed; true }
+        else if key == self.mine_key         { self.pilot.mine_is_pressed         = pressed; true }
+        else if key == self.missile_key      { self.pilot.missile_is_pressed      = pressed; true }
         else { false }
     }
 }
 
+// jagged outline shared by all asteroids, scaled by radius; the id picks a starting
+// offset into the table so neighbouring rocks don't look identical
+const ASTEROID_OUTLINE : [f32; 11] = [1.0, 0.85, 0.95, 0.75, 1.0, 0.9, 0.8, 1.0, 0.7, 0.95, 0.85];
+
+// flat saucer with a dome on top, in units of the saucer's radius
+const SAUCER_OUTLINE : [[f32; 2]; 8] = [[-1.0, 0.0], [-0.5, -0.35], [0.5, -0.35], [1.0, 0.0],
+                                        [0.5, 0.25], [0.3, 0.55], [-0.3, 0.55], [-0.5, 0.25]];
+
 fn main() {
+    let mut team = None;
+    let mut class_name = None;
     for argument in ::std::env::args().skip(1) {
         match argument.as_ref() {
             "server" => { ::server::run(); return; },
+            choice if choice.starts_with("team=")  => team = Some(choice["team=".len()..].parse().unwrap()),
+            choice if choice.starts_with("class=") => class_name = Some(choice["class=".len()..].to_string()),
             unknown  => panic!(format!("Unknown argument '{}'", unknown)),
         }
     }
-    client();
+    client(team, class_name);
 }
 
-fn client() {
+// team is the one to ask the server for, or None to be put wherever there is room.
+// the ship class is picked by name from the settings, the first one being the default
+fn client(team : Option<u8>, class_name : Option<String>) {
     use glium::DisplayBuild;
 
     let server_address = "0.0.0.0:9998".parse().unwrap();
     let socket = UdpSocket::v4().unwrap();
 
     let settings = load_settings("settings.json");
+    let ship_class = match class_name {
+        Some(name) => settings.ship_classes.iter().position(|class| class.name == name).expect("Unknown ship class"),
+        None       => 0,
+    };
+    // until the server sends its map, show the plain -1 to 1 square
+    let mut bounds = Bounds::new(2.0, 2.0, false);
+    let mut obstacles : Vec<Obstacle> = Vec::new();
+    let mut spawn_points : Vec<Vec2<f32>> = Vec::new();
+    let mut stations : Vec<Station> = Vec::new();
 
     let display = {
         let mut display_builder = glutin::WindowBuilder::new();
@@ -127,30 +184,51 @@ fn client() {
 
     let mut do_clear = true;
 
-    let uniforms = uniform! {
-        matrix: [
-            [0.4, 0.0, 0.0, 0.0],
-            [0.0, 0.5, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [0.0, 0.0, 0.0, 1.0f32]
-        ]
-    };
-
     let mut players = Vec::new();
 
     players.push(LocalPlayer::new(VirtualKeyCode::Left,
                                   VirtualKeyCode::Right,
                                   VirtualKeyCode::Up,
+                                  VirtualKeyCode::Down,
+                                  VirtualKeyCode::Slash,
+                                  VirtualKeyCode::Comma,
+                                  VirtualKeyCode::Period,
+                                  VirtualKeyCode::Space,
+                                  VirtualKeyCode::Return,
+                                  VirtualKeyCode::RShift,
+                                  VirtualKeyCode::RControl,
+                                  VirtualKeyCode::M,
+                                  VirtualKeyCode::L,
                                   Integrator::ForwardEuler));
 /*
     players.push(LocalPlayer::new(VirtualKeyCode::A,
                                   VirtualKeyCode::D,
                                   VirtualKeyCode::W,
+                                  VirtualKeyCode::S,
+                                  VirtualKeyCode::X,
+                                  VirtualKeyCode::Z,
+                                  VirtualKeyCode::C,
+                                  VirtualKeyCode::LShift,
+                                  VirtualKeyCode::Q,
+                                  VirtualKeyCode::E,
+                                  VirtualKeyCode::LControl,
+                                  VirtualKeyCode::Tab,
+                                  VirtualKeyCode::Key1,
                                   Integrator::Verlet));
 
     players.push(LocalPlayer::new(VirtualKeyCode::F,
                                   VirtualKeyCode::H,
                                   VirtualKeyCode::T,
+                                  VirtualKeyCode::B,
+                                  VirtualKeyCode::N,
+                                  VirtualKeyCode::J,
+                                  VirtualKeyCode::K,
+                                  VirtualKeyCode::G,
+                                  VirtualKeyCode::R,
+                                  VirtualKeyCode::Y,
+                                  VirtualKeyCode::V,
+                                  VirtualKeyCode::U,
+                                  VirtualKeyCode::O,
                                   Integrator::ForwardEuler));
 */
     for player in players.iter_mut() {
@@ -158,7 +236,25 @@ fn client() {
     }
 
     let mut buffer = Vec::new();
-    let mut remote_ships : Vec<Ship> = Vec::new();
+    let mut remote_ships : Vec<(u8, u8, Ship)> = Vec::new(); // id, team, ship
+    let mut remote_asteroids : Vec<Asteroid> = Vec::new();
+    let mut remote_bullets : Vec<Bullet> = Vec::new();
+    let mut remote_saucers : Vec<Saucer> = Vec::new();
+    let mut remote_power_ups : Vec<PowerUp> = Vec::new();
+    let mut remote_mines : Vec<Mine> = Vec::new();
+    let mut remote_missiles : Vec<Missile> = Vec::new();
+    let mut remote_gravity_wells : Vec<GravityWell> = Vec::new();
+    let mut ship_infos : Vec<(u8, u8)> = Vec::new(); // id, ship class
+    let mut player_id : Option<u8> = None;
+    let mut scoreboard : Vec<(u8, u8, Score)> = Vec::new(); // id, team, score
+    let mut wave = 0;
+    let mut phase = MatchPhase::Waiting;
+    let mut phase_time_left = 0;
+    let mut fuel : Option<f32> = None; // only when the server limits it
+    let mut integrity = 1f32; // of our own ship's hull, from 0 to 1
+    let mut hit_flashes : Vec<(u8, u64)> = Vec::new(); // ship id, when the flash ends in ns
+    let mut winners : Vec<bool> = Vec::new(); // alongside the scoreboard
+    let mut ready : Vec<bool> = Vec::new(); // alongside the scoreboard
     let mut last_message_timestamp = 0;
 
     loop {
@@ -179,6 +275,28 @@ fn client() {
 
             implement_vertex!(Vertex, position, color, rotation, global_position);
 
+            // axis aligned rectangle in world coordinates, for the overlays
+            fn push_rectangle(vertices : &mut Vec<Vertex>, indices : &mut Vec<u16>, min : [f32; 2], max : [f32; 2], color : [f32; 3]) {
+                let base_index = vertices.len() as u16;
+                for &i in [0, 1, 2, 0, 2, 3].iter() {
+                    indices.push(base_index + i);
+                }
+                vertices.push(Vertex { position: [min[0], min[1]], color: color, rotation: 0.0, global_position: [0.0, 0.0] });
+                vertices.push(Vertex { position: [max[0], min[1]], color: color, rotation: 0.0, global_position: [0.0, 0.0] });
+                vertices.push(Vertex { position: [max[0], max[1]], color: color, rotation: 0.0, global_position: [0.0, 0.0] });
+                vertices.push(Vertex { position: [min[0], max[1]], color: color, rotation: 0.0, global_position: [0.0, 0.0] });
+            }
+
+            fn push_ship_icon(vertices : &mut Vec<Vertex>, indices : &mut Vec<u16>, position : [f32; 2], scale : f32, color : [f32; 3]) {
+                let base_index = vertices.len() as u16;
+                for i in 0..3 {
+                    indices.push(base_index + i);
+                }
+                for point in SHIP_HULL.iter() {
+                    vertices.push(Vertex { position: [point[0] * scale, point[1] * scale], color: color, rotation: ::std::f32::consts::PI / 2.0, global_position: position });
+                }
+            }
+
             let mut vertices = Vec::new();
             let mut indices = Vec::new();
 
@@ -204,19 +322,321 @@ fn client() {
             */
 
             let since_message = (clock_ticks::precise_time_ns() - last_message_timestamp) as f32 / 1_000_000f32;
-            for ship in remote_ships.iter() {
+            let blink_off = (clock_ticks::precise_time_ns() / 100_000_000) % 2 == 0;
+            for &(id, team, ref ship) in remote_ships.iter() {
+                if ship.is_invulnerable() && blink_off {
+                    continue;
+                }
+
+                let class = match ship_infos.iter().find(|&&(info_id, _)| info_id == id) {
+                    Some(&(_, class)) => &settings.ship_classes[class as usize % settings.ship_classes.len()],
+                    None              => &settings.ship_classes[0],
+                };
+                let hull = class.hull();
+                let radius = class.radius();
+                let flashing = hit_flashes.iter().any(|&(flash_id, until)| flash_id == id && until > clock_ticks::precise_time_ns());
+                let color = if flashing { [1.0, 1.0, 1.0] } else { team_color(team) };
+
+                // dead reconning position
+                let position = bounds.wrap(ship.position + ship.velocity * since_message);
+                let rotation = ship.rotation + ship.rotational_velocity * since_message;
+
+                for image in bounds.images(position, radius).iter() {
+                    // fan around the center, so hulls only need to be star shaped
+                    let center_index = vertices.len() as u16;
+                    vertices.push(Vertex { position: [0.0, 0.0], color: color, rotation: rotation, global_position: *image.as_array() });
+
+                    for (i, point) in hull.iter().enumerate() {
+                        vertices.push(Vertex { position: *point.as_array(), color: color, rotation: rotation, global_position: *image.as_array() });
+
+                        indices.push(center_index);
+                        indices.push(center_index + 1 + i as u16);
+                        indices.push(center_index + 1 + ((i + 1) % hull.len()) as u16);
+                    }
+
+                    // shield as a ring of quads around the ship, and a blinking red one further
+                    // out on our own ship while a missile is locked on to it
+                    let mut rings = Vec::new();
+                    if ship.shield_active {
+                        rings.push(([0.4, 0.8, 1.0], radius * 1.3, radius * 1.5));
+                    }
+                    if Some(id) == player_id && !blink_off && remote_missiles.iter().any(|missile| missile.target == Some(id)) {
+                        rings.push(([1.0, 0.2, 0.2], radius * 1.7, radius * 1.9));
+                    }
+
+                    for &(color, inner, outer) in rings.iter() {
+                        let points = 16;
+                        let base_index = vertices.len() as u16;
+
+                        for i in 0..points {
+                            let angle = i as f32 * 2.0 * ::std::f32::consts::PI / points as f32;
+                            let (sin, cos) = (f32::sin(angle), f32::cos(angle));
+                            vertices.push(Vertex { position: [inner * cos, inner * sin], color: color, rotation: 0.0, global_position: *image.as_array() });
+                            vertices.push(Vertex { position: [outer * cos, outer * sin], color: color, rotation: 0.0, global_position: *image.as_array() });
+
+                            let next = (i + 1) % points;
+                            indices.push(base_index + 2 * i);
+                            indices.push(base_index + 2 * i + 1);
+                            indices.push(base_index + 2 * next + 1);
+                            indices.push(base_index + 2 * i);
+                            indices.push(base_index + 2 * next + 1);
+                            indices.push(base_index + 2 * next);
+                        }
+                    }
+                }
+            }
+
+            // the map: walls, obstacles and faint markers where ships spawn
+            if bounds.walled {
+                let (min, max, thickness) = (bounds.min, bounds.max, 0.02);
+                let color = [0.5, 0.5, 0.6];
+                push_rectangle(&mut vertices, &mut indices, [min.x, min.y], [max.x, min.y + thickness], color);
+                push_rectangle(&mut vertices, &mut indices, [min.x, max.y - thickness], [max.x, max.y], color);
+                push_rectangle(&mut vertices, &mut indices, [min.x, min.y], [min.x + thickness, max.y], color);
+                push_rectangle(&mut vertices, &mut indices, [max.x - thickness, min.y], [max.x, max.y], color);
+            }
+
+            for obstacle in obstacles.iter() {
+                let color = [0.35, 0.35, 0.45];
+                let base_index = vertices.len() as u16;
+                for point in obstacle.points.iter() {
+                    vertices.push(Vertex { position: *point.as_array(), color: color, rotation: 0.0, global_position: [0.0, 0.0] });
+                }
+                for i in 1..(obstacle.points.len() as u16 - 1) {
+                    indices.push(base_index);
+                    indices.push(base_index + i);
+                    indices.push(base_index + i + 1);
+                }
+            }
+
+            for point in spawn_points.iter() {
+                push_rectangle(&mut vertices, &mut indices, [point.x - 0.015, point.y - 0.015], [point.x + 0.015, point.y + 0.015], [0.2, 0.25, 0.2]);
+            }
+
+            // docking stations as a dim pad with a brighter square in the middle
+            for station in stations.iter() {
+                let (position, radius) = (station.position, station.radius);
+                let points = 24;
+                let center_index = vertices.len() as u16;
+                vertices.push(Vertex { position: [0.0, 0.0], color: [0.15, 0.3, 0.2], rotation: 0.0, global_position: *position.as_array() });
+
+                for i in 0..points {
+                    let angle = i as f32 * 2.0 * ::std::f32::consts::PI / points as f32;
+                    vertices.push(Vertex { position: [radius * f32::cos(angle), radius * f32::sin(angle)], color: [0.15, 0.3, 0.2], rotation: 0.0, global_position: *position.as_array() });
+
+                    indices.push(center_index);
+                    indices.push(center_index + 1 + i as u16);
+                    indices.push(center_index + 1 + ((i + 1) % points) as u16);
+                }
+
+                push_rectangle(&mut vertices, &mut indices, [position.x - 0.03, position.y - 0.03], [position.x + 0.03, position.y + 0.03], [0.3, 0.7, 0.4]);
+            }
+
+            for well in remote_gravity_wells.iter() {
+                let position = bounds.wrap(well.position + well.velocity * since_message);
+                let color = [0.5, 0.3, 0.8];
+                let points = 24;
+
+                for image in bounds.images(position, well.radius).iter() {
+                    let center_index = vertices.len() as u16;
+                    vertices.push(Vertex { position: [0.0, 0.0], color: color, rotation: 0.0, global_position: *image.as_array() });
+
+                    for i in 0..points {
+                        let angle = i as f32 * 2.0 * ::std::f32::consts::PI / points as f32;
+                        vertices.push(Vertex { position: [well.radius * f32::cos(angle), well.radius * f32::sin(angle)], color: color, rotation: 0.0, global_position: *image.as_array() });
+
+                        indices.push(center_index);
+                        indices.push(center_index + 1 + i as u16);
+                        indices.push(center_index + 1 + ((i + 1) % points) as u16);
+                    }
+                }
+            }
+
+            for asteroid in remote_asteroids.iter() {
+                let position = bounds.wrap(asteroid.position + asteroid.velocity * since_message);
+                let rotation = asteroid.rotation + asteroid.rotational_velocity * since_message;
+                let color = [0.6, 0.6, 0.6];
+                let points = ASTEROID_OUTLINE.len();
+
+                for image in bounds.images(position, asteroid.radius).iter() {
+                    let center_index = vertices.len() as u16;
+                    vertices.push(Vertex { position: [0.0, 0.0], color: color, rotation: rotation, global_position: *image.as_array() });
+
+                    for i in 0..points {
+                        let angle = i as f32 * 2.0 * ::std::f32::consts::PI / points as f32;
+                        let radius = asteroid.radius * ASTEROID_OUTLINE[(i + asteroid.id as usize) % points];
+                        vertices.push(Vertex { position: [radius * f32::cos(angle), radius * f32::sin(angle)], color: color, rotation: rotation, global_position: *image.as_array() });
+
+                        indices.push(center_index);
+                        indices.push(center_index + 1 + i as u16);
+                        indices.push(center_index + 1 + ((i + 1) % points) as u16);
+                    }
+                }
+            }
+
+            for saucer in remote_saucers.iter() {
+                let position = bounds.wrap(saucer.position + saucer.velocity * since_message);
+                let color = [0.3, 1.0, 0.3];
+
+                for image in bounds.images(position, saucer.radius).iter() {
+                    let center_index = vertices.len() as u16;
+                    vertices.push(Vertex { position: [0.0, 0.0], color: color, rotation: 0.0, global_position: *image.as_array() });
+
+                    let points = SAUCER_OUTLINE.len();
+                    for i in 0..points {
+                        let point = SAUCER_OUTLINE[i];
+                        vertices.push(Vertex { position: [point[0] * saucer.radius, point[1] * saucer.radius], color: color, rotation: 0.0, global_position: *image.as_array() });
+
+                        indices.push(center_index);
+                        indices.push(center_index + 1 + i as u16);
+                        indices.push(center_index + 1 + ((i + 1) % points) as u16);
+                    }
+                }
+            }
+
+            // each kind of power-up is a slowly spinning polygon with its own color and number of corners
+            let spin = (clock_ticks::precise_time_ns() / 1_000_000) as f32 * 0.002;
+            for power_up in remote_power_ups.iter() {
+                let (color, points) = match power_up.kind {
+                    POWER_UP_RAPID_FIRE  => ([1.0, 1.0, 0.3], 3),
+                    POWER_UP_SPREAD_SHOT => ([1.0, 0.6, 0.2], 4),
+                    POWER_UP_EXTRA_LIFE  => ([1.0, 0.4, 0.8], 5),
+                    POWER_UP_FUEL        => ([0.4, 1.0, 0.4], 8),
+                    _                    => ([0.4, 0.8, 1.0], 6),
+                };
+
+                for image in bounds.images(power_up.position, POWER_UP_RADIUS).iter() {
+                    let center_index = vertices.len() as u16;
+                    vertices.push(Vertex { position: [0.0, 0.0], color: color, rotation: spin, global_position: *image.as_array() });
+
+                    for i in 0..points {
+                        let angle = i as f32 * 2.0 * ::std::f32::consts::PI / points as f32;
+                        vertices.push(Vertex { position: [POWER_UP_RADIUS * f32::cos(angle), POWER_UP_RADIUS * f32::sin(angle)], color: color, rotation: spin, global_position: *image.as_array() });
+
+                        indices.push(center_index);
+                        indices.push(center_index + 1 + i as u16);
+                        indices.push(center_index + 1 + ((i + 1) % points) as u16);
+                    }
+                }
+            }
+
+            // missiles are slim darts in their owner's team color
+            for missile in remote_missiles.iter() {
+                let team = scoreboard.iter().find(|&&(id, _, _)| id == missile.owner).map_or(0, |&(_, team, _)| team);
+                let color = team_color(team);
+                let position = bounds.wrap(missile.position + missile.velocity * since_message);
+
+                for image in bounds.images(position, 0.03).iter() {
+                    let base_index = vertices.len() as u16;
+                    for i in 0..3 {
+                        indices.push(base_index + i);
+                    }
+
+                    vertices.push(Vertex { position: [-0.02, -0.008], color: color, rotation: missile.rotation, global_position: *image.as_array() });
+                    vertices.push(Vertex { position: [ 0.03,  0.000], color: color, rotation: missile.rotation, global_position: *image.as_array() });
+                    vertices.push(Vertex { position: [-0.02,  0.008], color: color, rotation: missile.rotation, global_position: *image.as_array() });
+                }
+            }
+
+            // mines are small diamonds in their owner's team color, dimmed until they are armed
+            for mine in remote_mines.iter() {
+                let team = scoreboard.iter().find(|&&(id, _, _)| id == mine.owner).map_or(0, |&(_, team, _)| team);
+                let brightness = if mine.is_armed() { 1.0 } else { 0.4 };
+                let color = team_color(team);
+                let color = [color[0] * brightness, color[1] * brightness, color[2] * brightness];
+
+                for image in bounds.images(mine.position, 0.02).iter() {
+                    let base_index = vertices.len() as u16;
+                    for &i in [0, 1, 2, 0, 2, 3].iter() {
+                        indices.push(base_index + i);
+                    }
+
+                    vertices.push(Vertex { position: [ 0.02,  0.00], color: color, rotation: 0.0, global_position: *image.as_array() });
+                    vertices.push(Vertex { position: [ 0.00,  0.02], color: color, rotation: 0.0, global_position: *image.as_array() });
+                    vertices.push(Vertex { position: [-0.02,  0.00], color: color, rotation: 0.0, global_position: *image.as_array() });
+                    vertices.push(Vertex { position: [ 0.00, -0.02], color: color, rotation: 0.0, global_position: *image.as_array() });
+                }
+            }
+
+            for bullet in remote_bullets.iter() {
                 let base_index = vertices.len() as u16;
                 for i in 0..3 {
                     indices.push(base_index + i);
                 }
 
-                // dead reconning position
-                let position = ship.position + ship.velocity * since_message;
-                let rotation = ship.rotation + ship.rotational_velocity * since_message;
+                let position = bounds.wrap(bullet.position + bullet.velocity * since_message);
+                let color = if bullet.owner.is_none() { [1.0, 0.4, 0.4] } else { [1.0, 1.0, 0.6] };
+
+                vertices.push(Vertex { position: [-0.008, -0.008], color: color, rotation: 0.0, global_position: *position.as_array() });
+                vertices.push(Vertex { position: [ 0.008, -0.008], color: color, rotation: 0.0, global_position: *position.as_array() });
+                vertices.push(Vertex { position: [ 0.000,  0.008], color: color, rotation: 0.0, global_position: *position.as_array() });
+            }
+
+            // remaining lives along the top left corner
+            if let Some(&(_, _, ref score)) = scoreboard.iter().find(|&&(id, _, _)| Some(id) == player_id) {
+                for life in 0..score.lives {
+                    let position = [bounds.min.x + 0.1 + life as f32 * 0.08, bounds.max.y - 0.1];
+                    push_ship_icon(&mut vertices, &mut indices, position, 1.0, [0.8, 0.8, 0.8]);
+                }
+            }
 
-                vertices.push(Vertex { position: [-0.05, -0.025], color: [1.0, 1.0, 1.0], rotation: rotation, global_position: *position.as_array() });
-                vertices.push(Vertex { position: [ 0.05,  0.000], color: [1.0, 1.0, 1.0], rotation: rotation, global_position: *position.as_array() });
-                vertices.push(Vertex { position: [-0.05,  0.025], color: [1.0, 1.0, 1.0], rotation: rotation, global_position: *position.as_array() });
+            // shield energy bar along the top right corner
+            if let Some(&(_, _, ref ship)) = remote_ships.iter().find(|&&(id, _, _)| Some(id) == player_id) {
+                let (left, top) = (bounds.max.x - 0.6, bounds.max.y - 0.08);
+                let right = left + 0.5 * ship.energy;
+                let color = if ship.shield_active { [0.4, 0.8, 1.0] } else { [0.2, 0.4, 0.5] };
+                push_rectangle(&mut vertices, &mut indices, [left, top - 0.04], [right, top], color);
+
+                // and the fuel gauge right below it, turning red when the tank is nearly empty
+                if let Some(fuel) = fuel {
+                    let right = left + 0.5 * fuel;
+                    let color = if fuel < 0.2 { [1.0, 0.3, 0.2] } else { [1.0, 0.7, 0.2] };
+                    push_rectangle(&mut vertices, &mut indices, [left, top - 0.1], [right, top - 0.06], color);
+                }
+
+                // the hull last, fading from green to red as it takes damage
+                let right = left + 0.5 * integrity;
+                let color = [1.0 - integrity, integrity, 0.2];
+                push_rectangle(&mut vertices, &mut indices, [left, top - 0.16], [right, top - 0.12], color);
+            }
+
+            // whatever the current match phase needs on top of the arena
+            let phase_time_left = (phase_time_left as f32 - since_message).max(0.0);
+            match phase {
+                MatchPhase::Waiting | MatchPhase::ReadyCheck => {
+                    // one ship per player in the middle, lit up once they are ready
+                    let count = scoreboard.len();
+                    for (index, &(_, team, _)) in scoreboard.iter().enumerate() {
+                        let x = (index as f32 - (count as f32 - 1.0) / 2.0) * 0.2;
+                        let lit = ready.get(index).cloned().unwrap_or(false);
+                        let color = if lit { team_color(team) } else { [0.3, 0.3, 0.3] };
+                        push_ship_icon(&mut vertices, &mut indices, [x, 0.0], 2.0, color);
+                    }
+                },
+                MatchPhase::Countdown => {
+                    // a bar for every second left
+                    let seconds = f32::ceil(phase_time_left / 1000.0) as u32;
+                    for second in 0..seconds {
+                        let x = (second as f32 - (seconds as f32 - 1.0) / 2.0) * 0.15;
+                        push_rectangle(&mut vertices, &mut indices, [x - 0.03, -0.15], [x + 0.03, 0.15], [1.0, 1.0, 1.0]);
+                    }
+                },
+                MatchPhase::InProgress => {
+                    if settings.match_rules.time_limit_ms > 0 {
+                        let fraction = phase_time_left / settings.match_rules.time_limit_ms as f32;
+                        let width = bounds.size().x - 0.2;
+                        let left = bounds.min.x + 0.1;
+                        push_rectangle(&mut vertices, &mut indices, [left, bounds.min.y + 0.05], [left + width * fraction, bounds.min.y + 0.07], [0.5, 0.5, 0.5]);
+                    }
+                },
+                MatchPhase::Results => {
+                    // the winners, big, in their team colors
+                    let winning_teams : Vec<u8> = scoreboard.iter().map(|&(_, team, _)| team).zip(winners.iter()).filter(|&(_, &winner)| winner).map(|(team, _)| team).collect();
+                    for (index, &team) in winning_teams.iter().enumerate() {
+                        let x = (index as f32 - (winning_teams.len() as f32 - 1.0) / 2.0) * 0.4;
+                        push_ship_icon(&mut vertices, &mut indices, [x, 0.0], 4.0, team_color(team));
+                    }
+                },
             }
 
             let vertex_buffer = glium::VertexBuffer::new(&display, &vertices).unwrap();
@@ -225,6 +645,16 @@ fn client() {
             (vertex_buffer, index_buffer)
         };
 
+        let size = bounds.size();
+        let uniforms = uniform! {
+            matrix: [
+                [2.0 / size.x, 0.0, 0.0, 0.0],
+                [0.0, 2.0 / size.y, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0f32]
+            ]
+        };
+
         target.draw(&vertex_buffer, &index_buffer, &program, &uniforms, &Default::default()).unwrap();
 
         target.finish().unwrap();
@@ -271,9 +701,22 @@ fn client() {
                 let mut message = ::capnp::message::Builder::new_default();
                 {
                     let mut p = message.init_root::<player_status::Builder>();
-                    p.set_throttle  (player.pilot.up_is_pressed);
-                    p.set_turn_left (player.pilot.left_is_pressed);
-                    p.set_turn_right(player.pilot.right_is_pressed);
+                    p.set_throttle    (player.pilot.up_is_pressed);
+                    p.set_turn_left   (player.pilot.left_is_pressed);
+                    p.set_turn_right  (player.pilot.right_is_pressed);
+                    p.set_reverse     (player.pilot.down_is_pressed);
+                    p.set_brake       (player.pilot.brake_is_pressed);
+                    p.set_strafe_left (player.pilot.strafe_left_is_pressed);
+                    p.set_strafe_right(player.pilot.strafe_right_is_pressed);
+                    p.set_fire        (player.pilot.fire_is_pressed);
+                    p.set_respawn     (player.pilot.respawn_is_pressed);
+                    p.set_hyperspace  (player.pilot.hyperspace_is_pressed);
+                    p.set_shield      (player.pilot.shield_is_pressed);
+                    p.set_drop_mine   (player.pilot.mine_is_pressed);
+                    p.set_fire_missile(player.pilot.missile_is_pressed);
+                    p.set_choose_team (team.is_some());
+                    p.set_team        (team.unwrap_or(0));
+                    p.set_ship_class  (ship_class as u8);
                 }
 
                 serialize_packed::write_message(&mut buffer, &message).unwrap();
@@ -300,16 +743,179 @@ fn client() {
                 let message = message_reader.get_root::<game_status::Reader>().unwrap();
 
                 last_message_timestamp = now;
+                player_id = Some(message.get_player_id());
+
+                if message.get_wave() != wave {
+                    wave = message.get_wave();
+                    println!("Wave {}", wave);
+                }
+
+                if MatchPhase::from_u8(message.get_phase()) != phase {
+                    phase = MatchPhase::from_u8(message.get_phase());
+                    match phase {
+                        MatchPhase::Waiting    => println!("Waiting for players"),
+                        MatchPhase::ReadyCheck => println!("Press respawn when ready"),
+                        MatchPhase::Countdown  => println!("Get ready!"),
+                        MatchPhase::InProgress => println!("Go!"),
+                        MatchPhase::Results    => println!("Match over"),
+                    }
+                }
+                phase_time_left = message.get_phase_time_left();
+                fuel = if message.get_fuel_limited() { Some(message.get_fuel()) } else { None };
+
+                let now = clock_ticks::precise_time_ns();
+                hit_flashes.retain(|&(_, until)| until > now);
+                for event_msg in message.get_damage_events().unwrap().iter() {
+                    hit_flashes.push((event_msg.get_ship(), now + 150_000_000));
+                }
+
                 remote_ships.clear();
 
                 for ship_msg in message.get_ships().unwrap().iter() {
-                    let mut ship = Ship::new();
+                    let mut ship = Ship::new(Vec2::new(ship_msg.get_x() , ship_msg.get_y()));
                     ship.rotation = ship_msg.get_ang();
                     ship.rotational_velocity = ship_msg.get_dang();
-                    ship.position = Vec2::new(ship_msg.get_x() , ship_msg.get_y());
                     ship.velocity = Vec2::new(ship_msg.get_dx(), ship_msg.get_dy());
+                    ship.invulnerable = if ship_msg.get_invulnerable() { 1 } else { 0 };
+                    ship.shield_active = ship_msg.get_shield();
+                    ship.energy = ship_msg.get_energy();
+                    if Some(ship_msg.get_id()) == player_id {
+                        integrity = ship_msg.get_hull();
+                    }
+
+                    remote_ships.push((ship_msg.get_id(), ship_msg.get_team(), ship));
+                }
+
+                remote_asteroids.clear();
+
+                for asteroid_msg in message.get_asteroids().unwrap().iter() {
+                    let mut asteroid = Asteroid::new(asteroid_msg.get_id(),
+                                                     asteroid_msg.get_size(),
+                                                     Vec2::new(asteroid_msg.get_x() , asteroid_msg.get_y()),
+                                                     Vec2::new(asteroid_msg.get_dx(), asteroid_msg.get_dy()),
+                                                     asteroid_msg.get_dang());
+                    asteroid.rotation = asteroid_msg.get_ang();
+                    asteroid.radius = asteroid_msg.get_radius();
+
+                    remote_asteroids.push(asteroid);
+                }
+
+                remote_bullets.clear();
+
+                for bullet_msg in message.get_bullets().unwrap().iter() {
+                    let owner = if bullet_msg.get_from_saucer() { None } else { Some(bullet_msg.get_owner()) };
+                    remote_bullets.push(Bullet::new(owner,
+                                                    Vec2::new(bullet_msg.get_x() , bullet_msg.get_y()),
+                                                    Vec2::new(bullet_msg.get_dx(), bullet_msg.get_dy()),
+                                                    0));
+                }
+
+                remote_saucers.clear();
+
+                for saucer_msg in message.get_saucers().unwrap().iter() {
+                    let radius = if saucer_msg.get_small() { settings.saucers.small_radius } else { settings.saucers.large_radius };
+                    remote_saucers.push(Saucer::new(saucer_msg.get_id(),
+                                                    saucer_msg.get_small(),
+                                                    radius,
+                                                    Vec2::new(saucer_msg.get_x() , saucer_msg.get_y()),
+                                                    Vec2::new(saucer_msg.get_dx(), saucer_msg.get_dy()),
+                                                    0f32));
+                }
+
+                if message.has_ship_infos() {
+                    ship_infos = message.get_ship_infos().unwrap().iter().map(|info_msg| {
+                        // go by the name first, our own settings may list the classes in another order
+                        let class_name = info_msg.get_class_name().unwrap();
+                        let class = match settings.ship_classes.iter().position(|class| class.name == class_name) {
+                            Some(index) => index as u8,
+                            None        => info_msg.get_ship_class(),
+                        };
+                        (info_msg.get_id(), class)
+                    }).collect();
+                }
+
+                if message.has_map() {
+                    let map_msg = message.get_map().unwrap();
+                    bounds = Bounds::new(map_msg.get_width(), map_msg.get_height(), map_msg.get_walled());
+
+                    obstacles.clear();
+                    for obstacle_msg in map_msg.get_obstacles().unwrap().iter() {
+                        let points = obstacle_msg.get_points().unwrap().iter().map(|point_msg| Vec2::new(point_msg.get_x(), point_msg.get_y())).collect();
+                        obstacles.push(Obstacle::new(points));
+                    }
 
-                    remote_ships.push(ship);
+                    spawn_points = map_msg.get_spawn_points().unwrap().iter().map(|point_msg| Vec2::new(point_msg.get_x(), point_msg.get_y())).collect();
+                    stations = map_msg.get_stations().unwrap().iter().map(|station_msg| Station::new(Vec2::new(station_msg.get_x(), station_msg.get_y()), station_msg.get_radius())).collect();
+                }
+
+                remote_gravity_wells.clear();
+
+                for well_msg in message.get_gravity_wells().unwrap().iter() {
+                    let mut well = GravityWell::new(Vec2::new(well_msg.get_x(), well_msg.get_y()), 0f32, 0f32, well_msg.get_radius(), 0f32);
+                    well.velocity = Vec2::new(well_msg.get_dx(), well_msg.get_dy());
+                    remote_gravity_wells.push(well);
+                }
+
+                remote_mines.clear();
+
+                for mine_msg in message.get_mines().unwrap().iter() {
+                    let arm_timer = if mine_msg.get_armed() { 0 } else { 1 };
+                    remote_mines.push(Mine::new(mine_msg.get_owner(), Vec2::new(mine_msg.get_x(), mine_msg.get_y()), arm_timer, 0));
+                }
+
+                remote_missiles.clear();
+
+                for missile_msg in message.get_missiles().unwrap().iter() {
+                    let target = if missile_msg.get_locked() { Some(missile_msg.get_target()) } else { None };
+                    remote_missiles.push(Missile::new(missile_msg.get_owner(),
+                                                      target,
+                                                      Vec2::new(missile_msg.get_x(), missile_msg.get_y()),
+                                                      Vec2::new(missile_msg.get_dx(), missile_msg.get_dy()),
+                                                      missile_msg.get_ang(),
+                                                      0,
+                                                      0));
+                }
+
+                remote_power_ups.clear();
+
+                for power_up_msg in message.get_power_ups().unwrap().iter() {
+                    remote_power_ups.push(PowerUp::new(power_up_msg.get_id(),
+                                                       power_up_msg.get_kind(),
+                                                       Vec2::new(power_up_msg.get_x(), power_up_msg.get_y()),
+                                                       0));
+                }
+
+                if message.has_scoreboard() {
+                    let mut new_scoreboard = Vec::new();
+                    winners.clear();
+                    ready.clear();
+                    for entry_msg in message.get_scoreboard().unwrap().iter() {
+                        let mut score = Score::new(entry_msg.get_lives() as u32);
+                        score.points = entry_msg.get_points();
+                        score.kills  = entry_msg.get_kills() as u32;
+                        score.deaths = entry_msg.get_deaths() as u32;
+                        new_scoreboard.push((entry_msg.get_id(), entry_msg.get_team(), score));
+                        winners.push(entry_msg.get_winner());
+                        ready.push(entry_msg.get_ready());
+                    }
+
+                    if new_scoreboard != scoreboard {
+                        scoreboard = new_scoreboard;
+
+                        println!("Scoreboard:");
+                        for &(id, team, ref score) in scoreboard.iter() {
+                            let marker = if Some(id) == player_id { "*" } else { " " };
+                            let lives = if score.is_game_over() { "game over".to_string() } else { format!("{} lives", score.lives) };
+                            println!("{} ship {:3} (team {}): {:7} points, {:3} kills, {:3} deaths, {}", marker, id, team, score.points, score.kills, score.deaths, lives);
+                        }
+
+                        let teams = team_scores(&scoreboard);
+                        if teams.len() > 1 {
+                            for &(team, ref score) in teams.iter() {
+                                println!("  team {}: {:7} points, {:3} kills, {:3} deaths", team, score.points, score.kills, score.deaths);
+                            }
+                        }
+                    }
                 }
             }
         }
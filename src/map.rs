@@ -0,0 +1,71 @@
+extern crate rustc_serialize;
+use std::io::prelude::*;
+use std::fs::File;
+use self::rustc_serialize::*;
+use nalgebra::Vec2;
+use settings::{Point, GravityWellSettings, StationSettings};
+use common::{Bounds, Obstacle, Station};
+use collision::is_convex;
+
+// the layout of an arena, loaded by the server and sent on to the clients
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct Map {
+    pub width:         f32,
+    pub height:        f32,
+    pub walled:        bool,            // bounce off the edges instead of wrapping around
+    pub obstacles:     Vec<Vec<Point>>, // convex polygons, in either winding order
+    pub spawn_points:  Vec<Point>,      // if empty, random points are tried instead
+    pub stations:      Vec<StationSettings>,
+    pub gravity_wells: Vec<GravityWellSettings>,
+}
+
+impl Map {
+    pub fn bounds(&self) -> Bounds {
+        Bounds::new(self.width, self.height, self.walled)
+    }
+
+    pub fn obstacles(&self) -> Vec<Obstacle> {
+        self.obstacles.iter()
+            .map(|points| Obstacle::new(points.iter().map(|point| Vec2::new(point.x, point.y)).collect()))
+            .collect()
+    }
+
+    pub fn stations(&self) -> Vec<Station> {
+        self.stations.iter()
+            .map(|station| Station::new(Vec2::new(station.x, station.y), station.radius))
+            .collect()
+    }
+}
+
+pub fn load_map(filename: &str) -> Map {
+    let mut f = File::open(filename).unwrap();
+    let mut s = String::new();
+    f.read_to_string(&mut s).unwrap();
+
+    let decoded: Map = json::decode(&s).unwrap();
+
+    if decoded.width <= 0f32 || decoded.height <= 0f32 {
+        panic!("Map {} needs a width and height above 0", filename);
+    }
+    for (index, obstacle) in decoded.obstacles.iter().enumerate() {
+        if obstacle.len() < 3 {
+            panic!("Obstacle {} in map {} needs at least 3 points", index, filename);
+        }
+        let points : Vec<Vec2<f32>> = obstacle.iter().map(|point| Vec2::new(point.x, point.y)).collect();
+        if !is_convex(&points) {
+            panic!("Obstacle {} in map {} needs to be convex", index, filename);
+        }
+    }
+    for (index, station) in decoded.stations.iter().enumerate() {
+        if station.radius <= 0f32 {
+            panic!("Station {} in map {} needs a radius above 0", index, filename);
+        }
+    }
+    for (index, well) in decoded.gravity_wells.iter().enumerate() {
+        if well.radius <= 0f32 {
+            panic!("Gravity well {} in map {} needs a radius above 0", index, filename);
+        }
+    }
+
+    decoded
+}
@@ -59,6 +59,2646 @@ pub mod game_status {
     pub fn has_ships(&self) -> bool {
       !self.reader.get_pointer_field(0).is_null()
     }
+    #[inline]
+    pub fn get_asteroids(self) -> Result<struct_list::Reader<'a,::network_capnp::asteroid::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1))
+    }
+    pub fn has_asteroids(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_bullets(self) -> Result<struct_list::Reader<'a,::network_capnp::bullet::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2))
+    }
+    pub fn has_bullets(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_player_id(self) -> u8 {
+      self.reader.get_data_field::<u8>(8)
+    }
+    #[inline]
+    pub fn get_scoreboard(self) -> Result<struct_list::Reader<'a,::network_capnp::score_entry::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3))
+    }
+    pub fn has_scoreboard(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_wave(self) -> u16 {
+      self.reader.get_data_field::<u16>(5)
+    }
+    #[inline]
+    pub fn get_saucers(self) -> Result<struct_list::Reader<'a,::network_capnp::saucer::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(4))
+    }
+    pub fn has_saucers(&self) -> bool {
+      !self.reader.get_pointer_field(4).is_null()
+    }
+    #[inline]
+    pub fn get_power_ups(self) -> Result<struct_list::Reader<'a,::network_capnp::power_up::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(5))
+    }
+    pub fn has_power_ups(&self) -> bool {
+      !self.reader.get_pointer_field(5).is_null()
+    }
+    #[inline]
+    pub fn get_phase(self) -> u8 {
+      self.reader.get_data_field::<u8>(9)
+    }
+    #[inline]
+    pub fn get_phase_time_left(self) -> u32 {
+      self.reader.get_data_field::<u32>(3)
+    }
+    #[inline]
+    pub fn get_players_ready(self) -> u8 {
+      self.reader.get_data_field::<u8>(16)
+    }
+    #[inline]
+    pub fn get_gravity_wells(self) -> Result<struct_list::Reader<'a,::network_capnp::gravity_well::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(6))
+    }
+    pub fn has_gravity_wells(&self) -> bool {
+      !self.reader.get_pointer_field(6).is_null()
+    }
+    #[inline]
+    pub fn get_map(self) -> Result<::network_capnp::map::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(7))
+    }
+    pub fn has_map(&self) -> bool {
+      !self.reader.get_pointer_field(7).is_null()
+    }
+    #[inline]
+    pub fn get_ship_infos(self) -> Result<struct_list::Reader<'a,::network_capnp::ship_info::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(8))
+    }
+    pub fn has_ship_infos(&self) -> bool {
+      !self.reader.get_pointer_field(8).is_null()
+    }
+    #[inline]
+    pub fn get_mines(self) -> Result<struct_list::Reader<'a,::network_capnp::mine::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(9))
+    }
+    pub fn has_mines(&self) -> bool {
+      !self.reader.get_pointer_field(9).is_null()
+    }
+    #[inline]
+    pub fn get_missiles(self) -> Result<struct_list::Reader<'a,::network_capnp::missile::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(10))
+    }
+    pub fn has_missiles(&self) -> bool {
+      !self.reader.get_pointer_field(10).is_null()
+    }
+    #[inline]
+    pub fn get_fuel(self) -> f32 {
+      self.reader.get_data_field::<f32>(5)
+    }
+    #[inline]
+    pub fn get_fuel_limited(self) -> bool {
+      self.reader.get_bool_field(136)
+    }
+    #[inline]
+    pub fn get_damage_events(self) -> Result<struct_list::Reader<'a,::network_capnp::damage_event::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(11))
+    }
+    pub fn has_damage_events(&self) -> bool {
+      !self.reader.get_pointer_field(11).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_timestamp(self) -> u64 {
+      self.builder.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn set_timestamp(&mut self, value : u64)  {
+      self.builder.set_data_field::<u64>(0, value);
+    }
+    #[inline]
+    pub fn get_ships(self) -> Result<struct_list::Builder<'a,::network_capnp::ship::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn set_ships(&mut self, value : struct_list::Reader<'a,::network_capnp::ship::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value)
+    }
+    #[inline]
+    pub fn init_ships(self, size : u32) -> struct_list::Builder<'a,::network_capnp::ship::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    pub fn has_ships(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_asteroids(self) -> Result<struct_list::Builder<'a,::network_capnp::asteroid::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1))
+    }
+    #[inline]
+    pub fn set_asteroids(&mut self, value : struct_list::Reader<'a,::network_capnp::asteroid::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value)
+    }
+    #[inline]
+    pub fn init_asteroids(self, size : u32) -> struct_list::Builder<'a,::network_capnp::asteroid::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    pub fn has_asteroids(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_bullets(self) -> Result<struct_list::Builder<'a,::network_capnp::bullet::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2))
+    }
+    #[inline]
+    pub fn set_bullets(&mut self, value : struct_list::Reader<'a,::network_capnp::bullet::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value)
+    }
+    #[inline]
+    pub fn init_bullets(self, size : u32) -> struct_list::Builder<'a,::network_capnp::bullet::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+    }
+    pub fn has_bullets(&self) -> bool {
+      !self.builder.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_player_id(self) -> u8 {
+      self.builder.get_data_field::<u8>(8)
+    }
+    #[inline]
+    pub fn set_player_id(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(8, value);
+    }
+    #[inline]
+    pub fn get_scoreboard(self) -> Result<struct_list::Builder<'a,::network_capnp::score_entry::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3))
+    }
+    #[inline]
+    pub fn set_scoreboard(&mut self, value : struct_list::Reader<'a,::network_capnp::score_entry::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(3), value)
+    }
+    #[inline]
+    pub fn init_scoreboard(self, size : u32) -> struct_list::Builder<'a,::network_capnp::score_entry::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(3), size)
+    }
+    pub fn has_scoreboard(&self) -> bool {
+      !self.builder.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_wave(self) -> u16 {
+      self.builder.get_data_field::<u16>(5)
+    }
+    #[inline]
+    pub fn set_wave(&mut self, value : u16)  {
+      self.builder.set_data_field::<u16>(5, value);
+    }
+    #[inline]
+    pub fn get_saucers(self) -> Result<struct_list::Builder<'a,::network_capnp::saucer::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(4))
+    }
+    #[inline]
+    pub fn set_saucers(&mut self, value : struct_list::Reader<'a,::network_capnp::saucer::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(4), value)
+    }
+    #[inline]
+    pub fn init_saucers(self, size : u32) -> struct_list::Builder<'a,::network_capnp::saucer::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(4), size)
+    }
+    pub fn has_saucers(&self) -> bool {
+      !self.builder.get_pointer_field(4).is_null()
+    }
+    #[inline]
+    pub fn get_power_ups(self) -> Result<struct_list::Builder<'a,::network_capnp::power_up::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(5))
+    }
+    #[inline]
+    pub fn set_power_ups(&mut self, value : struct_list::Reader<'a,::network_capnp::power_up::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(5), value)
+    }
+    #[inline]
+    pub fn init_power_ups(self, size : u32) -> struct_list::Builder<'a,::network_capnp::power_up::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), size)
+    }
+    pub fn has_power_ups(&self) -> bool {
+      !self.builder.get_pointer_field(5).is_null()
+    }
+    #[inline]
+    pub fn get_phase(self) -> u8 {
+      self.builder.get_data_field::<u8>(9)
+    }
+    #[inline]
+    pub fn set_phase(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(9, value);
+    }
+    #[inline]
+    pub fn get_phase_time_left(self) -> u32 {
+      self.builder.get_data_field::<u32>(3)
+    }
+    #[inline]
+    pub fn set_phase_time_left(&mut self, value : u32)  {
+      self.builder.set_data_field::<u32>(3, value);
+    }
+    #[inline]
+    pub fn get_players_ready(self) -> u8 {
+      self.builder.get_data_field::<u8>(16)
+    }
+    #[inline]
+    pub fn set_players_ready(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(16, value);
+    }
+    #[inline]
+    pub fn get_gravity_wells(self) -> Result<struct_list::Builder<'a,::network_capnp::gravity_well::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(6))
+    }
+    #[inline]
+    pub fn set_gravity_wells(&mut self, value : struct_list::Reader<'a,::network_capnp::gravity_well::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(6), value)
+    }
+    #[inline]
+    pub fn init_gravity_wells(self, size : u32) -> struct_list::Builder<'a,::network_capnp::gravity_well::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(6), size)
+    }
+    pub fn has_gravity_wells(&self) -> bool {
+      !self.builder.get_pointer_field(6).is_null()
+    }
+    #[inline]
+    pub fn get_map(self) -> Result<::network_capnp::map::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(7))
+    }
+    #[inline]
+    pub fn set_map(&mut self, value : ::network_capnp::map::Reader) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(7), value)
+    }
+    #[inline]
+    pub fn init_map(self, ) -> ::network_capnp::map::Builder<'a> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(7), 0)
+    }
+    pub fn has_map(&self) -> bool {
+      !self.builder.get_pointer_field(7).is_null()
+    }
+    #[inline]
+    pub fn get_ship_infos(self) -> Result<struct_list::Builder<'a,::network_capnp::ship_info::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(8))
+    }
+    #[inline]
+    pub fn set_ship_infos(&mut self, value : struct_list::Reader<'a,::network_capnp::ship_info::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(8), value)
+    }
+    #[inline]
+    pub fn init_ship_infos(self, size : u32) -> struct_list::Builder<'a,::network_capnp::ship_info::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(8), size)
+    }
+    pub fn has_ship_infos(&self) -> bool {
+      !self.builder.get_pointer_field(8).is_null()
+    }
+    #[inline]
+    pub fn get_mines(self) -> Result<struct_list::Builder<'a,::network_capnp::mine::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(9))
+    }
+    #[inline]
+    pub fn set_mines(&mut self, value : struct_list::Reader<'a,::network_capnp::mine::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(9), value)
+    }
+    #[inline]
+    pub fn init_mines(self, size : u32) -> struct_list::Builder<'a,::network_capnp::mine::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(9), size)
+    }
+    pub fn has_mines(&self) -> bool {
+      !self.builder.get_pointer_field(9).is_null()
+    }
+    #[inline]
+    pub fn get_missiles(self) -> Result<struct_list::Builder<'a,::network_capnp::missile::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(10))
+    }
+    #[inline]
+    pub fn set_missiles(&mut self, value : struct_list::Reader<'a,::network_capnp::missile::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(10), value)
+    }
+    #[inline]
+    pub fn init_missiles(self, size : u32) -> struct_list::Builder<'a,::network_capnp::missile::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(10), size)
+    }
+    pub fn has_missiles(&self) -> bool {
+      !self.builder.get_pointer_field(10).is_null()
+    }
+    #[inline]
+    pub fn get_fuel(self) -> f32 {
+      self.builder.get_data_field::<f32>(5)
+    }
+    #[inline]
+    pub fn set_fuel(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(5, value);
+    }
+    #[inline]
+    pub fn get_fuel_limited(self) -> bool {
+      self.builder.get_bool_field(136)
+    }
+    #[inline]
+    pub fn set_fuel_limited(&mut self, value : bool)  {
+      self.builder.set_bool_field(136, value);
+    }
+    #[inline]
+    pub fn get_damage_events(self) -> Result<struct_list::Builder<'a,::network_capnp::damage_event::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(11))
+    }
+    #[inline]
+    pub fn set_damage_events(&mut self, value : struct_list::Reader<'a,::network_capnp::damage_event::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(11), value)
+    }
+    #[inline]
+    pub fn init_damage_events(self, size : u32) -> struct_list::Builder<'a,::network_capnp::damage_event::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(11), size)
+    }
+    pub fn has_damage_events(&self) -> bool {
+      !self.builder.get_pointer_field(11).is_null()
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+    pub fn get_map(&self) -> ::network_capnp::map::Pipeline {
+      FromTypelessPipeline::new(self._typeless.get_pointer_field(7))
+    }
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 3, pointers : 12 };
+    pub const TYPE_ID: u64 = 0xc0de493a7b706050;
+  }
+}
+
+pub mod ship {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> u8 {
+      self.reader.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.reader.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn get_ang(self) -> f32 {
+      self.reader.get_data_field::<f32>(5)
+    }
+    #[inline]
+    pub fn get_dang(self) -> f32 {
+      self.reader.get_data_field::<f32>(6)
+    }
+    #[inline]
+    pub fn get_invulnerable(self) -> bool {
+      self.reader.get_bool_field(8)
+    }
+    #[inline]
+    pub fn get_shield(self) -> bool {
+      self.reader.get_bool_field(9)
+    }
+    #[inline]
+    pub fn get_energy(self) -> f32 {
+      self.reader.get_data_field::<f32>(7)
+    }
+    #[inline]
+    pub fn get_team(self) -> u8 {
+      self.reader.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn get_hull(self) -> f32 {
+      self.reader.get_data_field::<f32>(8)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> u8 {
+      self.builder.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn set_id(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(0, value);
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn set_dx(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(3, value);
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.builder.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn set_dy(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(4, value);
+    }
+    #[inline]
+    pub fn get_ang(self) -> f32 {
+      self.builder.get_data_field::<f32>(5)
+    }
+    #[inline]
+    pub fn set_ang(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(5, value);
+    }
+    #[inline]
+    pub fn get_dang(self) -> f32 {
+      self.builder.get_data_field::<f32>(6)
+    }
+    #[inline]
+    pub fn set_dang(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(6, value);
+    }
+    #[inline]
+    pub fn get_invulnerable(self) -> bool {
+      self.builder.get_bool_field(8)
+    }
+    #[inline]
+    pub fn set_invulnerable(&mut self, value : bool)  {
+      self.builder.set_bool_field(8, value);
+    }
+    #[inline]
+    pub fn get_shield(self) -> bool {
+      self.builder.get_bool_field(9)
+    }
+    #[inline]
+    pub fn set_shield(&mut self, value : bool)  {
+      self.builder.set_bool_field(9, value);
+    }
+    #[inline]
+    pub fn get_energy(self) -> f32 {
+      self.builder.get_data_field::<f32>(7)
+    }
+    #[inline]
+    pub fn set_energy(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(7, value);
+    }
+    #[inline]
+    pub fn get_team(self) -> u8 {
+      self.builder.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn set_team(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(2, value);
+    }
+    #[inline]
+    pub fn get_hull(self) -> f32 {
+      self.builder.get_data_field::<f32>(8)
+    }
+    #[inline]
+    pub fn set_hull(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(8, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 5, pointers : 0 };
+    pub const TYPE_ID: u64 = 0x9449fd611b161e9a;
+  }
+}
+
+pub mod asteroid {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> u16 {
+      self.reader.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.reader.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn get_ang(self) -> f32 {
+      self.reader.get_data_field::<f32>(5)
+    }
+    #[inline]
+    pub fn get_dang(self) -> f32 {
+      self.reader.get_data_field::<f32>(6)
+    }
+    #[inline]
+    pub fn get_radius(self) -> f32 {
+      self.reader.get_data_field::<f32>(7)
+    }
+    #[inline]
+    pub fn get_size(self) -> u8 {
+      self.reader.get_data_field::<u8>(2)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> u16 {
+      self.builder.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn set_id(&mut self, value : u16)  {
+      self.builder.set_data_field::<u16>(0, value);
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn set_dx(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(3, value);
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.builder.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn set_dy(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(4, value);
+    }
+    #[inline]
+    pub fn get_ang(self) -> f32 {
+      self.builder.get_data_field::<f32>(5)
+    }
+    #[inline]
+    pub fn set_ang(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(5, value);
+    }
+    #[inline]
+    pub fn get_dang(self) -> f32 {
+      self.builder.get_data_field::<f32>(6)
+    }
+    #[inline]
+    pub fn set_dang(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(6, value);
+    }
+    #[inline]
+    pub fn get_radius(self) -> f32 {
+      self.builder.get_data_field::<f32>(7)
+    }
+    #[inline]
+    pub fn set_radius(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(7, value);
+    }
+    #[inline]
+    pub fn get_size(self) -> u8 {
+      self.builder.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn set_size(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(2, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 4, pointers : 0 };
+    pub const TYPE_ID: u64 = 0xc2828561680f0e23;
+  }
+}
+
+pub mod bullet {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_owner(self) -> u8 {
+      self.reader.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.reader.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn get_from_saucer(self) -> bool {
+      self.reader.get_bool_field(8)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_owner(self) -> u8 {
+      self.builder.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn set_owner(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(0, value);
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn set_dx(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(3, value);
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.builder.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn set_dy(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(4, value);
+    }
+    #[inline]
+    pub fn get_from_saucer(self) -> bool {
+      self.builder.get_bool_field(8)
+    }
+    #[inline]
+    pub fn set_from_saucer(&mut self, value : bool)  {
+      self.builder.set_bool_field(8, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 3, pointers : 0 };
+    pub const TYPE_ID: u64 = 0xfa70051e30a5894d;
+  }
+}
+
+pub mod saucer {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> u16 {
+      self.reader.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.reader.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn get_small(self) -> bool {
+      self.reader.get_bool_field(16)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> u16 {
+      self.builder.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn set_id(&mut self, value : u16)  {
+      self.builder.set_data_field::<u16>(0, value);
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn set_dx(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(3, value);
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.builder.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn set_dy(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(4, value);
+    }
+    #[inline]
+    pub fn get_small(self) -> bool {
+      self.builder.get_bool_field(16)
+    }
+    #[inline]
+    pub fn set_small(&mut self, value : bool)  {
+      self.builder.set_bool_field(16, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 3, pointers : 0 };
+    pub const TYPE_ID: u64 = 0xda89f934f51fc396;
+  }
+}
+
+pub mod power_up {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> u16 {
+      self.reader.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn get_kind(self) -> u8 {
+      self.reader.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_id(self) -> u16 {
+      self.builder.get_data_field::<u16>(0)
+    }
+    #[inline]
+    pub fn set_id(&mut self, value : u16)  {
+      self.builder.set_data_field::<u16>(0, value);
+    }
+    #[inline]
+    pub fn get_kind(self) -> u8 {
+      self.builder.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn set_kind(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(2, value);
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 2, pointers : 0 };
+    pub const TYPE_ID: u64 = 0x89419ab10cf325f5;
+  }
+}
+
+pub mod damage_event {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_ship(self) -> u8 {
+      self.reader.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn get_amount(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_ship(self) -> u8 {
+      self.builder.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn set_ship(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(0, value);
+    }
+    #[inline]
+    pub fn get_amount(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_amount(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 1, pointers : 0 };
+    pub const TYPE_ID: u64 = 0x8b56ad4cc288bece;
+  }
+}
+
+pub mod mine {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_owner(self) -> u8 {
+      self.reader.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn get_armed(self) -> bool {
+      self.reader.get_bool_field(8)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_owner(self) -> u8 {
+      self.builder.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn set_owner(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(0, value);
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
+    }
+    #[inline]
+    pub fn get_armed(self) -> bool {
+      self.builder.get_bool_field(8)
+    }
+    #[inline]
+    pub fn set_armed(&mut self, value : bool)  {
+      self.builder.set_bool_field(8, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 2, pointers : 0 };
+    pub const TYPE_ID: u64 = 0x8cd1802b934c05e0;
+  }
+}
+
+pub mod missile {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_owner(self) -> u8 {
+      self.reader.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn get_target(self) -> u8 {
+      self.reader.get_data_field::<u8>(1)
+    }
+    #[inline]
+    pub fn get_locked(self) -> bool {
+      self.reader.get_bool_field(16)
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.reader.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn get_ang(self) -> f32 {
+      self.reader.get_data_field::<f32>(5)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_owner(self) -> u8 {
+      self.builder.get_data_field::<u8>(0)
+    }
+    #[inline]
+    pub fn set_owner(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(0, value);
+    }
+    #[inline]
+    pub fn get_target(self) -> u8 {
+      self.builder.get_data_field::<u8>(1)
+    }
+    #[inline]
+    pub fn set_target(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(1, value);
+    }
+    #[inline]
+    pub fn get_locked(self) -> bool {
+      self.builder.get_bool_field(16)
+    }
+    #[inline]
+    pub fn set_locked(&mut self, value : bool)  {
+      self.builder.set_bool_field(16, value);
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn set_dx(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(3, value);
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.builder.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn set_dy(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(4, value);
+    }
+    #[inline]
+    pub fn get_ang(self) -> f32 {
+      self.builder.get_data_field::<f32>(5)
+    }
+    #[inline]
+    pub fn set_ang(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(5, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 3, pointers : 0 };
+    pub const TYPE_ID: u64 = 0x83783d62049c2e6f;
+  }
+}
+
+pub mod gravity_well {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(0)
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.reader.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn get_radius(self) -> f32 {
+      self.reader.get_data_field::<f32>(4)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(0)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(0, value);
+    }
+    #[inline]
+    pub fn get_dx(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_dx(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
+    }
+    #[inline]
+    pub fn get_dy(self) -> f32 {
+      self.builder.get_data_field::<f32>(3)
+    }
+    #[inline]
+    pub fn set_dy(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(3, value);
+    }
+    #[inline]
+    pub fn get_radius(self) -> f32 {
+      self.builder.get_data_field::<f32>(4)
+    }
+    #[inline]
+    pub fn set_radius(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(4, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 3, pointers : 0 };
+    pub const TYPE_ID: u64 = 0xee3e2d78ab2a933c;
+  }
+}
+
+pub mod map {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_width(self) -> f32 {
+      self.reader.get_data_field::<f32>(0)
+    }
+    #[inline]
+    pub fn get_height(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_walled(self) -> bool {
+      self.reader.get_bool_field(64)
+    }
+    #[inline]
+    pub fn get_obstacles(self) -> Result<struct_list::Reader<'a,::network_capnp::obstacle::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0))
+    }
+    pub fn has_obstacles(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_spawn_points(self) -> Result<struct_list::Reader<'a,::network_capnp::point::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1))
+    }
+    pub fn has_spawn_points(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_stations(self) -> Result<struct_list::Reader<'a,::network_capnp::station::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2))
+    }
+    pub fn has_stations(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_width(self) -> f32 {
+      self.builder.get_data_field::<f32>(0)
+    }
+    #[inline]
+    pub fn set_width(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(0, value);
+    }
+    #[inline]
+    pub fn get_height(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_height(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+    #[inline]
+    pub fn get_walled(self) -> bool {
+      self.builder.get_bool_field(64)
+    }
+    #[inline]
+    pub fn set_walled(&mut self, value : bool)  {
+      self.builder.set_bool_field(64, value);
+    }
+    #[inline]
+    pub fn get_obstacles(self) -> Result<struct_list::Builder<'a,::network_capnp::obstacle::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn set_obstacles(&mut self, value : struct_list::Reader<'a,::network_capnp::obstacle::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value)
+    }
+    #[inline]
+    pub fn init_obstacles(self, size : u32) -> struct_list::Builder<'a,::network_capnp::obstacle::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    pub fn has_obstacles(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_spawn_points(self) -> Result<struct_list::Builder<'a,::network_capnp::point::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1))
+    }
+    #[inline]
+    pub fn set_spawn_points(&mut self, value : struct_list::Reader<'a,::network_capnp::point::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(1), value)
+    }
+    #[inline]
+    pub fn init_spawn_points(self, size : u32) -> struct_list::Builder<'a,::network_capnp::point::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(1), size)
+    }
+    pub fn has_spawn_points(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_stations(self) -> Result<struct_list::Builder<'a,::network_capnp::station::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2))
+    }
+    #[inline]
+    pub fn set_stations(&mut self, value : struct_list::Reader<'a,::network_capnp::station::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(2), value)
+    }
+    #[inline]
+    pub fn init_stations(self, size : u32) -> struct_list::Builder<'a,::network_capnp::station::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(2), size)
+    }
+    pub fn has_stations(&self) -> bool {
+      !self.builder.get_pointer_field(2).is_null()
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 2, pointers : 3 };
+    pub const TYPE_ID: u64 = 0xed1fcbd6d06aa983;
+  }
+}
+
+pub mod obstacle {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_points(self) -> Result<struct_list::Reader<'a,::network_capnp::point::Owned<>>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0))
+    }
+    pub fn has_points(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_points(self) -> Result<struct_list::Builder<'a,::network_capnp::point::Owned<>>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0))
+    }
+    #[inline]
+    pub fn set_points(&mut self, value : struct_list::Reader<'a,::network_capnp::point::Owned<>>) -> Result<()> {
+      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value)
+    }
+    #[inline]
+    pub fn init_points(self, size : u32) -> struct_list::Builder<'a,::network_capnp::point::Owned<>> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    }
+    pub fn has_points(&self) -> bool {
+      !self.builder.get_pointer_field(0).is_null()
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 0, pointers : 1 };
+    pub const TYPE_ID: u64 = 0xe3847233414eda92;
+  }
+}
+
+pub mod point {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(0)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+  }
+
+  pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
+  impl <'a,> ::capnp::traits::HasStructSize for Builder<'a,>
+  {
+    #[inline]
+    fn struct_size() -> layout::StructSize { _private::STRUCT_SIZE }
+  }
+  impl <'a,> ::capnp::traits::HasTypeId for Builder<'a,>
+   {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructBuilder<'a> for Builder<'a,>
+   {
+    fn new(builder : ::capnp::private::layout::StructBuilder<'a>) -> Builder<'a, > {
+      Builder { builder : builder,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>
+   {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size : u32) -> Builder<'a,> {
+      ::capnp::traits::FromStructBuilder::new(builder.init_struct(_private::STRUCT_SIZE))
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>) -> Result<Builder<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructBuilder::new(try!(builder.get_struct(_private::STRUCT_SIZE, ::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::SetPointerBuilder<Builder<'a,>> for Reader<'a,>
+   {
+    fn set_pointer_builder<'b>(pointer : ::capnp::private::layout::PointerBuilder<'b>, value : Reader<'a,>) -> Result<()> { pointer.set_struct(&value.reader) }
+  }
+
+  impl <'a,> Builder<'a,>
+   {
+    pub fn as_reader(self) -> Reader<'a,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+    pub fn borrow<'b>(&'b mut self) -> Builder<'b,> {
+      Builder { .. *self }
+    }
+    pub fn borrow_as_reader<'b>(&'b self) -> Reader<'b,> {
+      ::capnp::traits::FromStructReader::new(self.builder.as_reader())
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(0)
+    }
+    #[inline]
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(0, value);
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
+  impl FromTypelessPipeline for Pipeline {
+    fn new(typeless : ::capnp::any_pointer::Pipeline) -> Pipeline {
+      Pipeline { _typeless : typeless,  }
+    }
+  }
+  impl Pipeline {
+  }
+  mod _private {
+    use capnp::private::layout;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 1, pointers : 0 };
+    pub const TYPE_ID: u64 = 0xbf0bf5ec3c581563;
+  }
+}
+
+pub mod station {
+  #![allow(dead_code)]
+  #![allow(unused_imports)]
+  use capnp::capability::{FromClientHook, FromTypelessPipeline};
+  use capnp::{text, data, Result};
+  use capnp::private::layout;
+  use capnp::traits::{FromStructBuilder, FromStructReader};
+  use capnp::{primitive_list, enum_list, struct_list, text_list, data_list, list_list};
+
+  pub struct Owned;
+  impl <'a> ::capnp::traits::Owned<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl <'a> ::capnp::traits::OwnedStruct<'a> for Owned { type Reader = Reader<'a>; type Builder = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  #[derive(Clone, Copy)]
+  pub struct Reader<'a> { reader : layout::StructReader<'a> }
+
+  impl <'a,> ::capnp::traits::HasTypeId for Reader<'a,>
+  {
+    #[inline]
+    fn type_id() -> u64 { _private::TYPE_ID }
+  }
+  impl <'a,> ::capnp::traits::FromStructReader<'a> for Reader<'a,>
+  {
+    fn new(reader: ::capnp::private::layout::StructReader<'a>) -> Reader<'a,> {
+      Reader { reader : reader,  }
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>
+  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>) -> Result<Reader<'a,>> {
+      ::std::result::Result::Ok(::capnp::traits::FromStructReader::new(try!(reader.get_struct(::std::ptr::null()))))
+    }
+  }
+
+  impl <'a,> Reader<'a,>
+  {
+    pub fn borrow<'b>(&'b self) -> Reader<'b,> {
+      Reader { .. *self }
+    }
+
+    pub fn total_size(&self) -> Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_x(self) -> f32 {
+      self.reader.get_data_field::<f32>(0)
+    }
+    #[inline]
+    pub fn get_y(self) -> f32 {
+      self.reader.get_data_field::<f32>(1)
+    }
+    #[inline]
+    pub fn get_radius(self) -> f32 {
+      self.reader.get_data_field::<f32>(2)
+    }
   }
 
   pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
@@ -110,27 +2750,28 @@ pub mod game_status {
       self.builder.as_reader().total_size()
     }
     #[inline]
-    pub fn get_timestamp(self) -> u64 {
-      self.builder.get_data_field::<u64>(0)
+    pub fn get_x(self) -> f32 {
+      self.builder.get_data_field::<f32>(0)
     }
     #[inline]
-    pub fn set_timestamp(&mut self, value : u64)  {
-      self.builder.set_data_field::<u64>(0, value);
+    pub fn set_x(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(0, value);
     }
     #[inline]
-    pub fn get_ships(self) -> Result<struct_list::Builder<'a,::network_capnp::ship::Owned<>>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0))
+    pub fn get_y(self) -> f32 {
+      self.builder.get_data_field::<f32>(1)
     }
     #[inline]
-    pub fn set_ships(&mut self, value : struct_list::Reader<'a,::network_capnp::ship::Owned<>>) -> Result<()> {
-      ::capnp::traits::SetPointerBuilder::set_pointer_builder(self.builder.get_pointer_field(0), value)
+    pub fn set_y(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(1, value);
     }
     #[inline]
-    pub fn init_ships(self, size : u32) -> struct_list::Builder<'a,::network_capnp::ship::Owned<>> {
-      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(0), size)
+    pub fn get_radius(self) -> f32 {
+      self.builder.get_data_field::<f32>(2)
     }
-    pub fn has_ships(&self) -> bool {
-      !self.builder.get_pointer_field(0).is_null()
+    #[inline]
+    pub fn set_radius(&mut self, value : f32)  {
+      self.builder.set_data_field::<f32>(2, value);
     }
   }
 
@@ -144,12 +2785,12 @@ pub mod game_status {
   }
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 1, pointers : 1 };
-    pub const TYPE_ID: u64 = 0xc0de493a7b706050;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 2, pointers : 0 };
+    pub const TYPE_ID: u64 = 0x98ad695674ceb8f4;
   }
 }
 
-pub mod ship {
+pub mod score_entry {
   #![allow(dead_code)]
   #![allow(unused_imports)]
   use capnp::capability::{FromClientHook, FromTypelessPipeline};
@@ -199,28 +2840,32 @@ pub mod ship {
       self.reader.get_data_field::<u8>(0)
     }
     #[inline]
-    pub fn get_x(self) -> f32 {
-      self.reader.get_data_field::<f32>(1)
+    pub fn get_points(self) -> u32 {
+      self.reader.get_data_field::<u32>(1)
     }
     #[inline]
-    pub fn get_dx(self) -> f32 {
-      self.reader.get_data_field::<f32>(2)
+    pub fn get_kills(self) -> u16 {
+      self.reader.get_data_field::<u16>(1)
     }
     #[inline]
-    pub fn get_y(self) -> f32 {
-      self.reader.get_data_field::<f32>(3)
+    pub fn get_deaths(self) -> u16 {
+      self.reader.get_data_field::<u16>(4)
     }
     #[inline]
-    pub fn get_dy(self) -> f32 {
-      self.reader.get_data_field::<f32>(4)
+    pub fn get_lives(self) -> u8 {
+      self.reader.get_data_field::<u8>(1)
     }
     #[inline]
-    pub fn get_ang(self) -> f32 {
-      self.reader.get_data_field::<f32>(5)
+    pub fn get_team(self) -> u8 {
+      self.reader.get_data_field::<u8>(10)
     }
     #[inline]
-    pub fn get_dang(self) -> f32 {
-      self.reader.get_data_field::<f32>(6)
+    pub fn get_winner(self) -> bool {
+      self.reader.get_bool_field(88)
+    }
+    #[inline]
+    pub fn get_ready(self) -> bool {
+      self.reader.get_bool_field(89)
     }
   }
 
@@ -281,52 +2926,60 @@ pub mod ship {
       self.builder.set_data_field::<u8>(0, value);
     }
     #[inline]
-    pub fn get_x(self) -> f32 {
-      self.builder.get_data_field::<f32>(1)
+    pub fn get_points(self) -> u32 {
+      self.builder.get_data_field::<u32>(1)
     }
     #[inline]
-    pub fn set_x(&mut self, value : f32)  {
-      self.builder.set_data_field::<f32>(1, value);
+    pub fn set_points(&mut self, value : u32)  {
+      self.builder.set_data_field::<u32>(1, value);
     }
     #[inline]
-    pub fn get_dx(self) -> f32 {
-      self.builder.get_data_field::<f32>(2)
+    pub fn get_kills(self) -> u16 {
+      self.builder.get_data_field::<u16>(1)
     }
     #[inline]
-    pub fn set_dx(&mut self, value : f32)  {
-      self.builder.set_data_field::<f32>(2, value);
+    pub fn set_kills(&mut self, value : u16)  {
+      self.builder.set_data_field::<u16>(1, value);
     }
     #[inline]
-    pub fn get_y(self) -> f32 {
-      self.builder.get_data_field::<f32>(3)
+    pub fn get_deaths(self) -> u16 {
+      self.builder.get_data_field::<u16>(4)
     }
     #[inline]
-    pub fn set_y(&mut self, value : f32)  {
-      self.builder.set_data_field::<f32>(3, value);
+    pub fn set_deaths(&mut self, value : u16)  {
+      self.builder.set_data_field::<u16>(4, value);
     }
     #[inline]
-    pub fn get_dy(self) -> f32 {
-      self.builder.get_data_field::<f32>(4)
+    pub fn get_lives(self) -> u8 {
+      self.builder.get_data_field::<u8>(1)
     }
     #[inline]
-    pub fn set_dy(&mut self, value : f32)  {
-      self.builder.set_data_field::<f32>(4, value);
+    pub fn set_lives(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(1, value);
     }
     #[inline]
-    pub fn get_ang(self) -> f32 {
-      self.builder.get_data_field::<f32>(5)
+    pub fn get_team(self) -> u8 {
+      self.builder.get_data_field::<u8>(10)
     }
     #[inline]
-    pub fn set_ang(&mut self, value : f32)  {
-      self.builder.set_data_field::<f32>(5, value);
+    pub fn set_team(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(10, value);
     }
     #[inline]
-    pub fn get_dang(self) -> f32 {
-      self.builder.get_data_field::<f32>(6)
+    pub fn get_winner(self) -> bool {
+      self.builder.get_bool_field(88)
     }
     #[inline]
-    pub fn set_dang(&mut self, value : f32)  {
-      self.builder.set_data_field::<f32>(6, value);
+    pub fn set_winner(&mut self, value : bool)  {
+      self.builder.set_bool_field(88, value);
+    }
+    #[inline]
+    pub fn get_ready(self) -> bool {
+      self.builder.get_bool_field(89)
+    }
+    #[inline]
+    pub fn set_ready(&mut self, value : bool)  {
+      self.builder.set_bool_field(89, value);
     }
   }
 
@@ -340,8 +2993,8 @@ pub mod ship {
   }
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 4, pointers : 0 };
-    pub const TYPE_ID: u64 = 0x9449fd611b161e9a;
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 2, pointers : 0 };
+    pub const TYPE_ID: u64 = 0xb169efa3f1df2b30;
   }
 }
 
@@ -413,6 +3066,17 @@ pub mod ship_info {
     pub fn get_b(self) -> u8 {
       self.reader.get_data_field::<u8>(3)
     }
+    #[inline]
+    pub fn get_ship_class(self) -> u8 {
+      self.reader.get_data_field::<u8>(4)
+    }
+    #[inline]
+    pub fn get_class_name(self) -> Result<text::Reader<'a>> {
+      self.reader.get_pointer_field(1).get_text(::std::ptr::null(), 0)
+    }
+    pub fn has_class_name(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
   }
 
   pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
@@ -510,6 +3174,29 @@ pub mod ship_info {
     pub fn set_b(&mut self, value : u8)  {
       self.builder.set_data_field::<u8>(3, value);
     }
+    #[inline]
+    pub fn get_ship_class(self) -> u8 {
+      self.builder.get_data_field::<u8>(4)
+    }
+    #[inline]
+    pub fn set_ship_class(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(4, value);
+    }
+    #[inline]
+    pub fn get_class_name(self) -> Result<text::Builder<'a>> {
+      self.builder.get_pointer_field(1).get_text(::std::ptr::null(), 0)
+    }
+    #[inline]
+    pub fn set_class_name(&mut self, value : text::Reader)  {
+      self.builder.get_pointer_field(1).set_text(value);
+    }
+    #[inline]
+    pub fn init_class_name(self, size : u32) -> text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
+    }
+    pub fn has_class_name(&self) -> bool {
+      !self.builder.get_pointer_field(1).is_null()
+    }
   }
 
   pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
@@ -522,7 +3209,7 @@ pub mod ship_info {
   }
   mod _private {
     use capnp::private::layout;
-    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 1, pointers : 1 };
+    pub const STRUCT_SIZE : layout::StructSize = layout::StructSize { data : 1, pointers : 2 };
     pub const TYPE_ID: u64 = 0xc09fc190c058f377;
   }
 }
@@ -584,6 +3271,58 @@ pub mod player_status {
     pub fn get_turn_right(self) -> bool {
       self.reader.get_bool_field(2)
     }
+    #[inline]
+    pub fn get_fire(self) -> bool {
+      self.reader.get_bool_field(3)
+    }
+    #[inline]
+    pub fn get_respawn(self) -> bool {
+      self.reader.get_bool_field(4)
+    }
+    #[inline]
+    pub fn get_hyperspace(self) -> bool {
+      self.reader.get_bool_field(5)
+    }
+    #[inline]
+    pub fn get_shield(self) -> bool {
+      self.reader.get_bool_field(6)
+    }
+    #[inline]
+    pub fn get_choose_team(self) -> bool {
+      self.reader.get_bool_field(7)
+    }
+    #[inline]
+    pub fn get_team(self) -> u8 {
+      self.reader.get_data_field::<u8>(1)
+    }
+    #[inline]
+    pub fn get_ship_class(self) -> u8 {
+      self.reader.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn get_reverse(self) -> bool {
+      self.reader.get_bool_field(24)
+    }
+    #[inline]
+    pub fn get_brake(self) -> bool {
+      self.reader.get_bool_field(25)
+    }
+    #[inline]
+    pub fn get_strafe_left(self) -> bool {
+      self.reader.get_bool_field(26)
+    }
+    #[inline]
+    pub fn get_strafe_right(self) -> bool {
+      self.reader.get_bool_field(27)
+    }
+    #[inline]
+    pub fn get_drop_mine(self) -> bool {
+      self.reader.get_bool_field(28)
+    }
+    #[inline]
+    pub fn get_fire_missile(self) -> bool {
+      self.reader.get_bool_field(29)
+    }
   }
 
   pub struct Builder<'a> { builder : ::capnp::private::layout::StructBuilder<'a> }
@@ -658,6 +3397,110 @@ pub mod player_status {
     pub fn set_turn_right(&mut self, value : bool)  {
       self.builder.set_bool_field(2, value);
     }
+    #[inline]
+    pub fn get_fire(self) -> bool {
+      self.builder.get_bool_field(3)
+    }
+    #[inline]
+    pub fn set_fire(&mut self, value : bool)  {
+      self.builder.set_bool_field(3, value);
+    }
+    #[inline]
+    pub fn get_respawn(self) -> bool {
+      self.builder.get_bool_field(4)
+    }
+    #[inline]
+    pub fn set_respawn(&mut self, value : bool)  {
+      self.builder.set_bool_field(4, value);
+    }
+    #[inline]
+    pub fn get_hyperspace(self) -> bool {
+      self.builder.get_bool_field(5)
+    }
+    #[inline]
+    pub fn set_hyperspace(&mut self, value : bool)  {
+      self.builder.set_bool_field(5, value);
+    }
+    #[inline]
+    pub fn get_shield(self) -> bool {
+      self.builder.get_bool_field(6)
+    }
+    #[inline]
+    pub fn set_shield(&mut self, value : bool)  {
+      self.builder.set_bool_field(6, value);
+    }
+    #[inline]
+    pub fn get_choose_team(self) -> bool {
+      self.builder.get_bool_field(7)
+    }
+    #[inline]
+    pub fn set_choose_team(&mut self, value : bool)  {
+      self.builder.set_bool_field(7, value);
+    }
+    #[inline]
+    pub fn get_team(self) -> u8 {
+      self.builder.get_data_field::<u8>(1)
+    }
+    #[inline]
+    pub fn set_team(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(1, value);
+    }
+    #[inline]
+    pub fn get_ship_class(self) -> u8 {
+      self.builder.get_data_field::<u8>(2)
+    }
+    #[inline]
+    pub fn set_ship_class(&mut self, value : u8)  {
+      self.builder.set_data_field::<u8>(2, value);
+    }
+    #[inline]
+    pub fn get_reverse(self) -> bool {
+      self.builder.get_bool_field(24)
+    }
+    #[inline]
+    pub fn set_reverse(&mut self, value : bool)  {
+      self.builder.set_bool_field(24, value);
+    }
+    #[inline]
+    pub fn get_brake(self) -> bool {
+      self.builder.get_bool_field(25)
+    }
+    #[inline]
+    pub fn set_brake(&mut self, value : bool)  {
+      self.builder.set_bool_field(25, value);
+    }
+    #[inline]
+    pub fn get_strafe_left(self) -> bool {
+      self.builder.get_bool_field(26)
+    }
+    #[inline]
+    pub fn set_strafe_left(&mut self, value : bool)  {
+      self.builder.set_bool_field(26, value);
+    }
+    #[inline]
+    pub fn get_strafe_right(self) -> bool {
+      self.builder.get_bool_field(27)
+    }
+    #[inline]
+    pub fn set_strafe_right(&mut self, value : bool)  {
+      self.builder.set_bool_field(27, value);
+    }
+    #[inline]
+    pub fn get_drop_mine(self) -> bool {
+      self.builder.get_bool_field(28)
+    }
+    #[inline]
+    pub fn set_drop_mine(&mut self, value : bool)  {
+      self.builder.set_bool_field(28, value);
+    }
+    #[inline]
+    pub fn get_fire_missile(self) -> bool {
+      self.builder.get_bool_field(29)
+    }
+    #[inline]
+    pub fn set_fire_missile(&mut self, value : bool)  {
+      self.builder.set_bool_field(29, value);
+    }
   }
 
   pub struct Pipeline { _typeless : ::capnp::any_pointer::Pipeline }
@@ -3,20 +3,901 @@ extern crate nalgebra;
 extern crate rustc_serialize;
 extern crate capnp;
 extern crate mio;
+extern crate rand;
 
 use std::collections::HashMap;
 use std::net::SocketAddr;
-use nalgebra::Vec2;
+use nalgebra::{Vec2, norm};
 use mio::udp::*;
 use mio::buf::SliceBuf;
-use settings::load_settings;
+use settings::{load_settings, Settings};
+use map::{load_map, Map};
+use game_mode::Outcome;
 use common::*;
+use collision::*;
 use network_capnp::{player_status, game_status};
 use capnp::serialize_packed;
+use rand::Rng;
 
 struct Client {
+	id                 : u8,
 	last_message       : u64,
 	pilot              : Pilot,
+	score              : Score,
+	team               : u8,
+	ready              : bool,
+}
+
+impl Client {
+    fn lose_ship(&mut self, settings : &Settings) {
+        self.pilot.destroy(settings.respawn_delay_ms);
+        self.score.deaths += 1;
+        if settings.game_mode.uses_lives() {
+            self.score.lives = self.score.lives.saturating_sub(1);
+        }
+        if self.score.is_game_over() {
+            println!("Game over for client {}", self.id);
+        }
+    }
+}
+
+// how far a point is from the closest of the ships, measured across the wrapped edges
+fn distance_to_ships(ships : &[Vec2<f32>], point : Vec2<f32>, bounds : &Bounds) -> f32 {
+    ships.iter()
+        .map(|&ship| norm(&(bounds.nearest_image(point, ship) - point)))
+        .fold(::std::f32::INFINITY, |a, b| a.min(b))
+}
+
+fn random_asteroid(id : u16, speed : f32, ships : &[Vec2<f32>], arena : &Arena, bounds : &Bounds, settings : &Settings) -> Asteroid {
+    let mut rng = rand::thread_rng();
+
+    // keep clear of the ships so nobody gets a rock dropped on them, and of wells and
+    // obstacles so it isn't swallowed or broken up straight away; settles for the
+    // roomiest spot tried if the arena is crowded
+    let radius = Asteroid::radius_for_size(ASTEROID_LARGE);
+    let room = |candidate : Vec2<f32>| -> f32 {
+        let from_ships = distance_to_ships(ships, candidate, bounds) - 0.5f32;
+        let from_wells = arena.gravity_wells.iter()
+            .map(|well| norm(&(bounds.nearest_image(candidate, well.position) - candidate)) - well.radius - 0.1f32)
+            .fold(::std::f32::INFINITY, |a, b| a.min(b));
+        let from_obstacles = arena.obstacles.iter()
+            .map(|obstacle| distance_to_polygon(bounds.nearest_image(obstacle.center, candidate), &obstacle.points) - 0.1f32)
+            .fold(::std::f32::INFINITY, |a, b| a.min(b));
+
+        from_ships.min(from_wells).min(from_obstacles) - radius
+    };
+
+    let mut position = Vec2::new(0f32, 0f32);
+    let mut best_room = ::std::f32::NEG_INFINITY;
+    for _ in 0..100 {
+        let candidate = Vec2::new(rng.gen_range(bounds.min.x, bounds.max.x), rng.gen_range(bounds.min.y, bounds.max.y));
+        let candidate_room = room(candidate);
+        if candidate_room > best_room {
+            position = candidate;
+            best_room = candidate_room;
+        }
+        if best_room >= 0f32 {
+            break;
+        }
+    }
+
+    let heading = rng.gen_range(0f32, 2f32 * ::std::f32::consts::PI);
+    let velocity = Vec2::new(f32::cos(heading), f32::sin(heading)) * speed;
+    let spin = rng.gen_range(-settings.asteroid_spin, settings.asteroid_spin);
+
+    Asteroid::new(id, ASTEROID_LARGE, position, velocity, spin)
+}
+
+// the layout loaded from the map, which stays put from one match to the next
+struct Arena {
+    gravity_wells : Vec<GravityWell>,
+    obstacles     : Vec<Obstacle>,
+    spawn_points  : Vec<Vec2<f32>>,
+    stations      : Vec<Station>,
+}
+
+impl Arena {
+    fn new(map : &Map) -> Arena {
+        Arena {
+            gravity_wells : map.gravity_wells.iter().map(|well| {
+                let orbit_speed = if well.orbit_period_ms > 0 { 2f32 * ::std::f32::consts::PI / well.orbit_period_ms as f32 } else { 0f32 };
+                GravityWell::new(Vec2::new(well.x, well.y), well.orbit_radius, orbit_speed, well.radius, well.strength)
+            }).collect(),
+            obstacles     : map.obstacles(),
+            spawn_points  : map.spawn_points.iter().map(|point| Vec2::new(point.x, point.y)).collect(),
+            stations      : map.stations(),
+        }
+    }
+}
+
+// everything in the arena that comes and goes during a match, other than the ships
+struct World {
+    asteroids        : Vec<Asteroid>,
+    bullets          : Vec<Bullet>,
+    saucers          : Vec<Saucer>,
+    power_ups        : Vec<PowerUp>,
+    mines            : Vec<Mine>,
+    missiles         : Vec<Missile>,
+    next_asteroid_id : u16,
+    next_saucer_id   : u16,
+    next_power_up_id : u16,
+    wave             : u32,
+    intermission     : Option<u64>, // None while a wave is being played
+    saucer_timer     : u64,
+    power_up_timer   : u64,
+    damage_events    : Vec<(u8, f32)>, // ship id and hull points lost, since the last message
+}
+
+impl World {
+    fn new(settings : &Settings) -> World {
+        World {
+            asteroids        : Vec::new(),
+            bullets          : Vec::new(),
+            saucers          : Vec::new(),
+            power_ups        : Vec::new(),
+            mines            : Vec::new(),
+            missiles         : Vec::new(),
+            next_asteroid_id : 0,
+            next_saucer_id   : 0,
+            next_power_up_id : 0,
+            wave             : 0,
+            intermission     : Some(0),
+            saucer_timer     : settings.saucers.spawn_interval_ms,
+            power_up_timer   : settings.power_ups.spawn_interval_max_ms,
+            damage_events    : Vec::new(),
+        }
+    }
+
+    // clears out everything left over from the last match
+    fn restart(&mut self, settings : &Settings) {
+        *self = World::new(settings);
+    }
+
+    fn spawn_wave(&mut self, ships : &[Vec2<f32>], arena : &Arena, bounds : &Bounds, settings : &Settings) {
+        self.wave += 1;
+
+        let waves = &settings.waves;
+        let count = ::std::cmp::min(waves.first_wave_asteroids + waves.asteroids_per_wave * (self.wave - 1), waves.max_asteroids);
+        let speed = waves.first_wave_speed + waves.speed_per_wave * (self.wave - 1) as f32;
+
+        println!("Starting wave {} with {} asteroids", self.wave, count);
+        for _ in 0..count {
+            self.asteroids.push(random_asteroid(self.next_asteroid_id, speed, ships, arena, bounds, settings));
+            self.next_asteroid_id = self.next_asteroid_id.wrapping_add(1);
+        }
+    }
+
+    fn step_waves(&mut self, ships : &[Vec2<f32>], arena : &Arena, bounds : &Bounds, settings : &Settings) {
+        self.intermission = match self.intermission {
+            None if self.asteroids.is_empty() => Some(settings.waves.intermission_ms),
+            None                              => None,
+            Some(0) => {
+                self.spawn_wave(ships, arena, bounds, settings);
+                None
+            },
+            Some(left) => Some(left - 1),
+        };
+    }
+
+    // saucers come in from the left or right edge and cross the arena once
+    fn spawn_saucer(&mut self, bounds : &Bounds, settings : &Settings) {
+        let mut rng = rand::thread_rng();
+        let saucers = &settings.saucers;
+
+        let small = self.wave >= saucers.small_from_wave && rng.gen();
+        let radius = if small { saucers.small_radius } else { saucers.large_radius };
+        let from_left : bool = rng.gen();
+        let x = if from_left { bounds.min.x } else { bounds.max.x };
+        let position = Vec2::new(x, rng.gen_range(bounds.min.y, bounds.max.y));
+        let velocity = Vec2::new(if from_left { saucers.speed } else { -saucers.speed }, 0f32);
+
+        self.saucers.push(Saucer::new(self.next_saucer_id, small, radius, position, velocity, bounds.size().x));
+        self.next_saucer_id = self.next_saucer_id.wrapping_add(1);
+    }
+
+    fn step_saucers(&mut self, targets : &[Vec2<f32>], bounds : &Bounds, settings : &Settings) {
+        let mut rng = rand::thread_rng();
+        let saucers = &settings.saucers;
+
+        if self.intermission.is_none() && self.saucers.is_empty() {
+            if self.saucer_timer == 0 {
+                self.spawn_saucer(bounds, settings);
+                self.saucer_timer = saucers.spawn_interval_ms;
+            }
+            else {
+                self.saucer_timer -= 1;
+            }
+        }
+
+        // aim gets better with every wave, small saucers are twice as accurate
+        let inaccuracy = (saucers.inaccuracy - saucers.inaccuracy_per_wave * (::std::cmp::max(self.wave, 1) - 1) as f32).max(saucers.min_inaccuracy);
+
+        for saucer in self.saucers.iter_mut() {
+            saucer.step(bounds);
+
+            if saucer.course_timer == 0 {
+                let vertical = [-0.75f32, 0f32, 0.75f32][rng.gen_range(0, 3)];
+                saucer.velocity.y = saucers.speed * vertical;
+                saucer.course_timer = saucers.course_change_ms;
+            }
+
+            if saucer.fire_cooldown == 0 && !targets.is_empty() {
+                let mut target = bounds.nearest_image(saucer.position, targets[0]);
+                for &other in targets.iter().skip(1) {
+                    let other = bounds.nearest_image(saucer.position, other);
+                    if norm(&(other - saucer.position)) < norm(&(target - saucer.position)) {
+                        target = other;
+                    }
+                }
+
+                let error = inaccuracy * if saucer.small { 0.5f32 } else { 1f32 };
+                let delta = target - saucer.position;
+                let offset = if error > 0f32 { rng.gen_range(-error, error) } else { 0f32 }; // a perfect shot otherwise
+                let angle = f32::atan2(delta.y, delta.x) + offset;
+                let direction = Vec2::new(f32::cos(angle), f32::sin(angle));
+
+                self.bullets.push(Bullet::new(None,
+                                              saucer.position + direction * saucer.radius,
+                                              direction * saucers.bullet_speed,
+                                              settings.bullet_lifetime_ms));
+                saucer.fire_cooldown = saucers.fire_cooldown_ms;
+            }
+        }
+
+        self.saucers.retain(|saucer| !saucer.has_left());
+    }
+
+    // a random kind of power-up shows up somewhere random every once in a while
+    fn step_power_ups(&mut self, bounds : &Bounds, settings : &Settings) {
+        let mut rng = rand::thread_rng();
+        let power_ups = &settings.power_ups;
+
+        if self.power_up_timer == 0 {
+            if self.power_ups.len() < power_ups.max_power_ups {
+                // only the kinds that are any use in this game
+                let mut kinds = vec![POWER_UP_RAPID_FIRE, POWER_UP_SPREAD_SHOT, POWER_UP_SHIELD_RECHARGE];
+                if settings.game_mode.uses_lives() {
+                    kinds.push(POWER_UP_EXTRA_LIFE);
+                }
+                if settings.fuel.enabled {
+                    kinds.push(POWER_UP_FUEL);
+                }
+                let kind = kinds[rng.gen_range(0, kinds.len())];
+                let position = Vec2::new(rng.gen_range(bounds.min.x, bounds.max.x), rng.gen_range(bounds.min.y, bounds.max.y));
+                self.power_ups.push(PowerUp::new(self.next_power_up_id, kind, position, power_ups.lifetime_ms));
+                self.next_power_up_id = self.next_power_up_id.wrapping_add(1);
+            }
+            self.power_up_timer = rng.gen_range(power_ups.spawn_interval_min_ms, power_ups.spawn_interval_max_ms + 1);
+        }
+        else {
+            self.power_up_timer -= 1;
+        }
+
+        for power_up in self.power_ups.iter_mut() {
+            power_up.step();
+        }
+        self.power_ups.retain(|power_up| !power_up.is_expired());
+    }
+}
+
+// somewhere random that is well clear of every obstacle, for jumping to through hyperspace;
+// gives up and takes the last try if the map is too crowded
+fn random_open_position(arena : &Arena, bounds : &Bounds) -> Vec2<f32> {
+    let mut rng = rand::thread_rng();
+    let mut position = Vec2::new(0f32, 0f32);
+    for _ in 0..100 {
+        position = Vec2::new(rng.gen_range(bounds.min.x, bounds.max.x), rng.gen_range(bounds.min.y, bounds.max.y));
+        if arena.obstacles.iter().all(|obstacle| distance_to_polygon(bounds.nearest_image(obstacle.center, position), &obstacle.points) > 0.1) {
+            break;
+        }
+    }
+    position
+}
+
+// picks the candidate spawn point with the most room around it, i.e. the one furthest
+// from the closest ship or asteroid surface, measured across the wrapped edges
+fn pick_spawn_point(clients  : &HashMap<SocketAddr, Client>,
+                    world    : &World,
+                    arena    : &Arena,
+                    bounds   : &Bounds,
+                    settings : &Settings) -> Vec2<f32> {
+    let candidates : Vec<Vec2<f32>> = if arena.spawn_points.is_empty() {
+        let mut rng = rand::thread_rng();
+        (0..settings.spawn_candidates)
+            .map(|_| Vec2::new(rng.gen_range(bounds.min.x, bounds.max.x), rng.gen_range(bounds.min.y, bounds.max.y)))
+            .collect()
+    }
+    else {
+        arena.spawn_points.clone()
+    };
+
+    let ships : Vec<Vec2<f32>> = clients.values()
+        .filter_map(|client| client.pilot.ship.as_ref())
+        .map(|ship| ship.position)
+        .collect();
+
+    let clearance = |candidate : Vec2<f32>| -> f32 {
+        let distance_to = |position : Vec2<f32>| norm(&(bounds.nearest_image(candidate, position) - candidate));
+
+        let from_ships = distance_to_ships(&ships, candidate, bounds);
+        let from_asteroids = world.asteroids.iter()
+            .map(|asteroid| distance_to(asteroid.position) - asteroid.radius)
+            .fold(::std::f32::INFINITY, |a, b| a.min(b));
+        let from_saucers = world.saucers.iter()
+            .map(|saucer| distance_to(saucer.position) - saucer.radius)
+            .fold(::std::f32::INFINITY, |a, b| a.min(b));
+        let from_wells = arena.gravity_wells.iter()
+            .map(|well| distance_to(well.position) - well.radius)
+            .fold(::std::f32::INFINITY, |a, b| a.min(b));
+        let from_obstacles = arena.obstacles.iter()
+            .map(|obstacle| distance_to_polygon(bounds.nearest_image(obstacle.center, candidate), &obstacle.points))
+            .fold(::std::f32::INFINITY, |a, b| a.min(b));
+
+        from_ships.min(from_asteroids).min(from_saucers).min(from_wells).min(from_obstacles)
+    };
+
+    let mut best = candidates[0];
+    let mut best_clearance = clearance(best);
+    for &candidate in candidates.iter().skip(1) {
+        let candidate_clearance = clearance(candidate);
+        if candidate_clearance > best_clearance {
+            best = candidate;
+            best_clearance = candidate_clearance;
+        }
+    }
+    best
+}
+
+// the first ship touching a power-up gets it
+fn collect_power_ups(clients  : &mut HashMap<SocketAddr, Client>,
+                     world    : &mut World,
+                     bounds   : &Bounds,
+                     settings : &Settings) {
+    let previous = ::std::mem::replace(&mut world.power_ups, Vec::new());
+    for power_up in previous.into_iter() {
+        let collector = clients.values_mut().find(|client| match client.pilot.ship {
+            Some(ref ship) => circle_intersects_polygon(bounds.nearest_image(ship.position, power_up.position), POWER_UP_RADIUS, &ship.hull()),
+            None           => false,
+        });
+
+        match collector {
+            Some(client) => {
+                match power_up.kind {
+                    POWER_UP_RAPID_FIRE      => client.pilot.rapid_fire_timer = settings.power_ups.rapid_fire_ms,
+                    POWER_UP_SPREAD_SHOT     => client.pilot.spread_shot_timer = settings.power_ups.spread_shot_ms,
+                    POWER_UP_EXTRA_LIFE      => client.score.lives += 1,
+                    POWER_UP_SHIELD_RECHARGE => {
+                        if let Some(ref mut ship) = client.pilot.ship {
+                            ship.energy = 1f32;
+                        }
+                    },
+                    POWER_UP_FUEL            => {
+                        if let Some(ref mut ship) = client.pilot.ship {
+                            ship.fuel = (ship.fuel + settings.fuel.pickup_amount).min(1f32);
+                        }
+                    },
+                    _ => {},
+                }
+            },
+            None => world.power_ups.push(power_up),
+        }
+    }
+}
+
+// the nearest hostile ship within the lock-on cone ahead of a launching ship; ships
+// are (client id, team, position)
+fn acquire_target(ships      : &[(u8, u8, Vec2<f32>)],
+                  owner      : u8,
+                  owner_team : u8,
+                  position   : Vec2<f32>,
+                  rotation   : f32,
+                  bounds     : &Bounds,
+                  settings   : &Settings) -> Option<u8> {
+    let missiles = &settings.missiles;
+    ships.iter()
+        .filter(|&&(id, team, _)| settings.game_mode.is_hostile(owner, owner_team, id, team))
+        .map(|&(id, _, target)| (id, bounds.nearest_image(position, target) - position))
+        .filter(|&(_, delta)| norm(&delta) <= missiles.lock_range &&
+                              f32::abs(angle_difference(rotation, f32::atan2(delta.y, delta.x))) <= missiles.lock_cone)
+        .fold(None, |nearest : Option<(u8, f32)>, (id, delta)| {
+            let distance = norm(&delta);
+            match nearest {
+                Some((_, best)) if best <= distance => nearest,
+                _                                   => Some((id, distance)),
+            }
+        })
+        .map(|(id, _)| id)
+}
+
+// what destroying an asteroid of the given size is worth, 0 for a size without points
+fn asteroid_points(points : &[u32], size : u8) -> u32 {
+    (size as usize).checked_sub(1).and_then(|index| points.get(index)).cloned().unwrap_or(0)
+}
+
+fn resolve_collisions(clients  : &mut HashMap<SocketAddr, Client>,
+                      world    : &mut World,
+                      arena    : &Arena,
+                      bounds   : &Bounds,
+                      settings : &Settings) {
+    let hulls : Vec<(u8, u8, Vec2<f32>, Vec<Vec2<f32>>)> = clients.values()
+        .filter_map(|client| client.pilot.ship.as_ref().map(|ship| (client.id, client.team, ship)))
+        .filter(|&(_, _, ship)| !ship.is_invulnerable())
+        .map(|(id, team, ship)| (id, team, ship.position, ship.hull()))
+        .collect();
+
+    // bullets of clients who have since left count as hostile to everyone
+    let teams : HashMap<u8, u8> = clients.values().map(|client| (client.id, client.team)).collect();
+    let hostile = |attacker : u8, victim : u8, victim_team : u8| {
+        teams.get(&attacker).map_or(true, |&attacker_team| settings.game_mode.is_hostile(attacker, attacker_team, victim, victim_team))
+    };
+
+    // damage done to ships: victim, hull points, and who dealt it if anyone
+    let mut hits : Vec<(u8, f32, Option<u8>)> = Vec::new();
+    // for asteroids and saucers, whether they were hit and who gets the points
+    let mut hit_asteroids = vec![false; world.asteroids.len()];
+    let mut asteroid_credit : Vec<Option<u8>> = vec![None; world.asteroids.len()];
+    let mut hit_saucers = vec![false; world.saucers.len()];
+    let mut saucer_credit : Vec<Option<u8>> = vec![None; world.saucers.len()];
+    let mut spent_bullets = vec![false; world.bullets.len()];
+    let mut spent_mines = vec![false; world.mines.len()];
+    let mut spent_missiles = vec![false; world.missiles.len()];
+
+    for (b, bullet) in world.bullets.iter().enumerate() {
+        if let Some(a) = world.asteroids.iter().position(|asteroid| point_in_circle(bounds.nearest_image(asteroid.position, bullet.position), asteroid.position, asteroid.radius)) {
+            hit_asteroids[a] = true;
+            asteroid_credit[a] = bullet.owner;
+            spent_bullets[b] = true;
+        }
+        else if let Some(s) = world.saucers.iter().position(|saucer| bullet.owner.is_some() && point_in_circle(bounds.nearest_image(saucer.position, bullet.position), saucer.position, saucer.radius)) {
+            hit_saucers[s] = true;
+            saucer_credit[s] = bullet.owner;
+            spent_bullets[b] = true;
+        }
+        else if let Some(&(id, _, _, _)) = hulls.iter().find(|&&(id, team, position, ref hull)| bullet.owner.map_or(true, |owner| hostile(owner, id, team)) && point_in_polygon(bounds.nearest_image(position, bullet.position), hull)) {
+            hits.push((id, settings.damage.bullet, bullet.owner));
+            spent_bullets[b] = true;
+        }
+    }
+
+    // missiles hit the same things bullets do
+    for (m, missile) in world.missiles.iter().enumerate() {
+        if let Some(a) = world.asteroids.iter().position(|asteroid| point_in_circle(bounds.nearest_image(asteroid.position, missile.position), asteroid.position, asteroid.radius)) {
+            hit_asteroids[a] = true;
+            asteroid_credit[a] = Some(missile.owner);
+            spent_missiles[m] = true;
+        }
+        else if let Some(s) = world.saucers.iter().position(|saucer| point_in_circle(bounds.nearest_image(saucer.position, missile.position), saucer.position, saucer.radius)) {
+            hit_saucers[s] = true;
+            saucer_credit[s] = Some(missile.owner);
+            spent_missiles[m] = true;
+        }
+        else if let Some(&(id, _, _, _)) = hulls.iter().find(|&&(id, team, position, ref hull)| hostile(missile.owner, id, team) && point_in_polygon(bounds.nearest_image(position, missile.position), hull)) {
+            hits.push((id, settings.damage.missile, Some(missile.owner)));
+            spent_missiles[m] = true;
+        }
+    }
+
+    // an armed mine goes off as soon as a hostile ship or any asteroid comes within reach,
+    // and hits all of them
+    for (m, mine) in world.mines.iter().enumerate().filter(|&(_, mine)| mine.is_armed()) {
+        let radius = settings.mines.radius;
+        let ships_in_reach : Vec<u8> = hulls.iter()
+            .filter(|&&(id, team, position, ref hull)| hostile(mine.owner, id, team) && circle_intersects_polygon(bounds.nearest_image(position, mine.position), radius, hull))
+            .map(|&(id, _, _, _)| id)
+            .collect();
+        let asteroids_in_reach : Vec<usize> = world.asteroids.iter().enumerate()
+            .filter(|&(_, asteroid)| circles_overlap(bounds.nearest_image(mine.position, asteroid.position), asteroid.radius, mine.position, radius))
+            .map(|(a, _)| a)
+            .collect();
+
+        if ships_in_reach.is_empty() && asteroids_in_reach.is_empty() {
+            continue;
+        }
+
+        spent_mines[m] = true;
+        for &id in ships_in_reach.iter() {
+            hits.push((id, settings.damage.mine, Some(mine.owner)));
+        }
+        for &a in asteroids_in_reach.iter() {
+            hit_asteroids[a] = true;
+            asteroid_credit[a] = Some(mine.owner);
+        }
+    }
+
+    // ships bounce off asteroids and off each other, taking damage the harder and heavier
+    // whatever they hit is; velocities are (displacement, mass) by client id
+    let bodies : HashMap<u8, (Vec2<f32>, f32)> = clients.values()
+        .filter_map(|client| client.pilot.ship.as_ref().map(|ship| (client.id, (ship.displacement(), ship.mass))))
+        .collect();
+    let restitution = settings.collisions.restitution;
+    let mut ship_impulses : Vec<(u8, Vec2<f32>)> = Vec::new();
+    let mut asteroid_impulses = vec![Vec2::new(0f32, 0f32); world.asteroids.len()];
+
+    for (i, &(id, _, position, ref hull)) in hulls.iter().enumerate() {
+        let (velocity, mass) = bodies[&id];
+
+        for (a, asteroid) in world.asteroids.iter().enumerate() {
+            let center = bounds.nearest_image(position, asteroid.position);
+            let delta = center - position;
+            if !circle_intersects_polygon(center, asteroid.radius, hull) || delta == Vec2::new(0f32, 0f32) {
+                continue;
+            }
+
+            let normal = delta * (1f32 / norm(&delta));
+            let asteroid_mass = asteroid.radius * asteroid.radius * settings.collisions.asteroid_density;
+            if let Some((ship_change, asteroid_change, impact)) = bounce(normal, velocity, mass, asteroid.velocity, asteroid_mass, restitution) {
+                ship_impulses.push((id, ship_change));
+                asteroid_impulses[a] = asteroid_impulses[a] + asteroid_change;
+
+                hits.push((id, impact * asteroid_mass * settings.collisions.impact_damage, None));
+                if impact >= settings.collisions.asteroid_break_speed {
+                    hit_asteroids[a] = true;
+                    asteroid_credit[a] = Some(id);
+                }
+            }
+        }
+
+        for (s, saucer) in world.saucers.iter().enumerate() {
+            if circle_intersects_polygon(bounds.nearest_image(position, saucer.position), saucer.radius, hull) {
+                hit_saucers[s] = true;
+                saucer_credit[s] = Some(id);
+                hits.push((id, settings.damage.ram, None));
+            }
+        }
+
+        // friendly ships bump into each other harmlessly
+        for &(other_id, other_team, other_position, ref other_hull) in hulls.iter().skip(i + 1) {
+            let offset = bounds.nearest_image(position, other_position) - other_position;
+            let other_hull : Vec<Vec2<f32>> = other_hull.iter().map(|&point| point + offset).collect();
+            let delta = other_position + offset - position;
+            if !polygons_intersect(hull, &other_hull) || delta == Vec2::new(0f32, 0f32) {
+                continue;
+            }
+
+            let normal = delta * (1f32 / norm(&delta));
+            let (other_velocity, other_mass) = bodies[&other_id];
+            if let Some((change, other_change, impact)) = bounce(normal, velocity, mass, other_velocity, other_mass, restitution) {
+                ship_impulses.push((id, change));
+                ship_impulses.push((other_id, other_change));
+
+                if hostile(id, other_id, other_team) {
+                    hits.push((id, impact * other_mass * settings.collisions.impact_damage, None));
+                    hits.push((other_id, impact * mass * settings.collisions.impact_damage, None));
+                }
+            }
+        }
+    }
+
+    // anything touching a gravity well is gone for good: asteroids don't split, and ships
+    // are worn down fast with no help from their shields
+    let mut hazard_hits : Vec<(u8, f32, Option<u8>)> = Vec::new();
+    let mut swallowed_asteroids = vec![false; world.asteroids.len()];
+    for well in arena.gravity_wells.iter() {
+        for &(id, _, position, ref hull) in hulls.iter() {
+            if circle_intersects_polygon(bounds.nearest_image(position, well.position), well.radius, hull) {
+                hazard_hits.push((id, settings.damage.hazard_per_ms, None));
+            }
+        }
+        for (b, bullet) in world.bullets.iter().enumerate() {
+            if point_in_circle(bounds.nearest_image(well.position, bullet.position), well.position, well.radius) {
+                spent_bullets[b] = true;
+            }
+        }
+        for (m, missile) in world.missiles.iter().enumerate() {
+            if point_in_circle(bounds.nearest_image(well.position, missile.position), well.position, well.radius) {
+                spent_missiles[m] = true;
+            }
+        }
+        for (a, asteroid) in world.asteroids.iter().enumerate() {
+            if circles_overlap(bounds.nearest_image(well.position, asteroid.position), asteroid.radius, well.position, well.radius) {
+                swallowed_asteroids[a] = true;
+            }
+        }
+        for (s, saucer) in world.saucers.iter().enumerate() {
+            if circles_overlap(bounds.nearest_image(well.position, saucer.position), saucer.radius, well.position, well.radius) {
+                hit_saucers[s] = true;
+            }
+        }
+    }
+
+    // obstacles are solid: ships are pushed back out and bounce off, scraping some hull
+    // off unless they are invulnerable; bullets stop and rocks and saucers break up
+    let mut ship_pushes : Vec<(u8, Vec2<f32>)> = Vec::new();
+    let solid_hulls : Vec<(u8, bool, Vec2<f32>, Vec<Vec2<f32>>)> = clients.values()
+        .filter_map(|client| client.pilot.ship.as_ref().map(|ship| (client.id, ship.is_invulnerable(), ship.position, ship.hull())))
+        .collect();
+    for obstacle in arena.obstacles.iter() {
+        for &(id, invulnerable, position, ref hull) in solid_hulls.iter() {
+            let offset = bounds.nearest_image(obstacle.center, position) - position;
+            let hull : Vec<Vec2<f32>> = hull.iter().map(|&point| point + offset).collect();
+            if !polygons_intersect(&hull, &obstacle.points) {
+                continue;
+            }
+
+            if !invulnerable {
+                hazard_hits.push((id, settings.damage.hazard_per_ms, None));
+            }
+
+            // out is away from the nearest bit of wall, or towards it if the center is
+            // already inside
+            let center = position + offset;
+            let closest = closest_point_on_polygon(center, &obstacle.points);
+            let out = if point_in_polygon(center, &obstacle.points) { closest - center } else { center - closest };
+            if out == Vec2::new(0f32, 0f32) {
+                continue;
+            }
+            let normal = out * (1f32 / norm(&out));
+
+            let mut push = 0f32;
+            while push < 1f32 && polygons_intersect(&hull.iter().map(|&point| point + normal * push).collect::<Vec<Vec2<f32>>>(), &obstacle.points) {
+                push += 0.005;
+            }
+            ship_pushes.push((id, normal * push));
+
+            // the wall doesn't budge, as if it were infinitely heavy
+            let (velocity, mass) = bodies[&id];
+            if let Some((change, _, _)) = bounce(normal * -1f32, velocity, mass, Vec2::new(0f32, 0f32), ::std::f32::INFINITY, restitution) {
+                ship_impulses.push((id, change));
+            }
+        }
+        for (b, bullet) in world.bullets.iter().enumerate() {
+            if point_in_polygon(bounds.nearest_image(obstacle.center, bullet.position), &obstacle.points) {
+                spent_bullets[b] = true;
+            }
+        }
+        for (m, missile) in world.missiles.iter().enumerate() {
+            if point_in_polygon(bounds.nearest_image(obstacle.center, missile.position), &obstacle.points) {
+                spent_missiles[m] = true;
+            }
+        }
+        for (a, asteroid) in world.asteroids.iter().enumerate() {
+            if circle_intersects_polygon(bounds.nearest_image(obstacle.center, asteroid.position), asteroid.radius, &obstacle.points) {
+                hit_asteroids[a] = true;
+            }
+        }
+        for (s, saucer) in world.saucers.iter().enumerate() {
+            if circle_intersects_polygon(bounds.nearest_image(obstacle.center, saucer.position), saucer.radius, &obstacle.points) {
+                hit_saucers[s] = true;
+            }
+        }
+    }
+
+    for (s, saucer) in world.saucers.iter().enumerate() {
+        for (a, asteroid) in world.asteroids.iter().enumerate() {
+            if circles_overlap(bounds.nearest_image(saucer.position, asteroid.position), asteroid.radius, saucer.position, saucer.radius) {
+                hit_saucers[s] = true;
+                hit_asteroids[a] = true;
+            }
+        }
+    }
+
+    // shielded ships take part in collisions like any other, they just don't get hurt
+    hits.retain(|&(victim, _, _)| {
+        clients.values().all(|client| client.id != victim || client.pilot.ship.as_ref().map_or(true, |ship| !ship.shield_active))
+    });
+    hits.extend(hazard_hits.into_iter());
+
+    // victims whose hull gave out, along with whoever hit them last if anyone did
+    let mut destroyed_ships : Vec<(u8, Option<u8>)> = Vec::new();
+    for client in clients.values_mut() {
+        let id = client.id;
+        if let Some(ref mut ship) = client.pilot.ship {
+            for &(_, change) in ship_impulses.iter().filter(|&&(impulse_id, _)| impulse_id == id) {
+                ship.apply_impulse(change);
+            }
+            for &(_, push) in ship_pushes.iter().filter(|&&(push_id, _)| push_id == id) {
+                ship.shift(push);
+            }
+
+            let damage = hits.iter().filter(|&&(victim, _, _)| victim == id).fold(0f32, |total, &(_, amount, _)| total + amount);
+            if damage > 0f32 {
+                ship.hull_points -= damage;
+                // one event per ship and message, however many steps it took damage in
+                match world.damage_events.iter().position(|&(event_id, _)| event_id == id) {
+                    Some(index) => world.damage_events[index].1 += damage,
+                    None        => world.damage_events.push((id, damage)),
+                }
+
+                if ship.hull_points <= 0f32 {
+                    let killer = hits.iter().filter(|&&(victim, _, _)| victim == id).filter_map(|&(_, _, attacker)| attacker).last();
+                    destroyed_ships.push((id, killer));
+                }
+            }
+        }
+    }
+
+    for client in clients.values_mut() {
+        for &(victim, killer) in destroyed_ships.iter() {
+            if client.id == victim {
+                client.lose_ship(settings);
+            }
+            else if killer == Some(client.id) {
+                client.score.kills += 1;
+                client.score.points += settings.game_mode.ship_points(settings);
+            }
+        }
+
+        for (asteroid, credit) in world.asteroids.iter().zip(asteroid_credit.iter()) {
+            if *credit == Some(client.id) && settings.game_mode.scores_asteroids() {
+                client.score.points += asteroid_points(&settings.asteroid_points, asteroid.size);
+            }
+        }
+
+        for (saucer, credit) in world.saucers.iter().zip(saucer_credit.iter()) {
+            if *credit == Some(client.id) {
+                client.score.points += if saucer.small { settings.saucers.small_points } else { settings.saucers.large_points };
+            }
+        }
+    }
+
+    let mut index = 0;
+    world.bullets.retain(|_| { index += 1; !spent_bullets[index - 1] });
+
+    let mut index = 0;
+    world.missiles.retain(|_| { index += 1; !spent_missiles[index - 1] });
+
+    let mut index = 0;
+    world.mines.retain(|_| { index += 1; !spent_mines[index - 1] });
+
+    let mut index = 0;
+    world.saucers.retain(|_| { index += 1; !hit_saucers[index - 1] });
+
+    for (asteroid, &change) in world.asteroids.iter_mut().zip(asteroid_impulses.iter()) {
+        asteroid.velocity = asteroid.velocity + change;
+    }
+
+    let previous = ::std::mem::replace(&mut world.asteroids, Vec::new());
+    for ((asteroid, hit), swallowed) in previous.into_iter().zip(hit_asteroids.into_iter()).zip(swallowed_asteroids.into_iter()) {
+        if swallowed {
+            continue;
+        }
+        else if !hit {
+            world.asteroids.push(asteroid);
+        }
+        else if let Some((first, second)) = asteroid.split(world.next_asteroid_id, world.next_asteroid_id.wrapping_add(1)) {
+            world.asteroids.push(first);
+            world.asteroids.push(second);
+            world.next_asteroid_id = world.next_asteroid_id.wrapping_add(2);
+        }
+    }
+}
+
+// the lowest id no connected client has, so ids of clients that left get reused
+fn free_client_id(clients : &HashMap<SocketAddr, Client>) -> Option<u8> {
+    (0..256u32).map(|id| id as u8).find(|&id| clients.values().all(|client| client.id != id))
+}
+
+// the team with the fewest players, or whichever one the client asked for
+fn pick_team(clients : &HashMap<SocketAddr, Client>, requested : Option<u8>, settings : &Settings) -> u8 {
+    let team_count = settings.game_mode.team_count();
+    match requested {
+        Some(team) => team % team_count,
+        None       => (0..team_count)
+            .map(|team| (clients.values().filter(|client| client.team == team).count(), team))
+            .fold((::std::usize::MAX, 0), |smallest, candidate| if candidate.0 < smallest.0 { candidate } else { smallest })
+            .1,
+    }
+}
+
+// wipes the arena and everyone's score and puts all ships back in for a fresh round
+fn start_round(clients  : &mut HashMap<SocketAddr, Client>,
+               world    : &mut World,
+               arena    : &Arena,
+               bounds   : &Bounds,
+               settings : &Settings) {
+    world.restart(settings);
+
+    let addresses : Vec<SocketAddr> = clients.keys().cloned().collect();
+    for address in addresses {
+        let position = pick_spawn_point(clients, world, arena, bounds, settings);
+        if let Some(client) = clients.get_mut(&address) {
+            client.score = Score::new(settings.starting_lives);
+            client.pilot.destroy(0);
+            client.pilot.spawn(position, settings.spawn_invulnerability_ms).ok();
+        }
+    }
+}
+
+// the arena is only simulated while a match is in progress, the other phases just wait
+// for players or for their timer to run out
+struct Match {
+    phase   : MatchPhase,
+    timer   : u64,     // time left in the current phase, if it is a timed one
+    winners : Vec<u8>, // of the last match
+}
+
+impl Match {
+    fn new() -> Match {
+        Match {
+            phase   : MatchPhase::Waiting,
+            timer   : 0,
+            winners : Vec::new(),
+        }
+    }
+
+    fn enter(&mut self, phase : MatchPhase, timer : u64) {
+        println!("Match phase: {:?}", phase);
+        self.phase = phase;
+        self.timer = timer;
+    }
+
+    fn step(&mut self,
+            clients  : &mut HashMap<SocketAddr, Client>,
+            world    : &mut World,
+            arena    : &Arena,
+            bounds   : &Bounds,
+            settings : &Settings) {
+        let rules = &settings.match_rules;
+        let enough_players = clients.len() >= ::std::cmp::max(rules.min_players, 1);
+
+        match self.phase {
+            MatchPhase::Waiting => {
+                if enough_players {
+                    for client in clients.values_mut() {
+                        client.ready = false;
+                    }
+                    self.enter(MatchPhase::ReadyCheck, 0);
+                }
+            },
+            MatchPhase::ReadyCheck => {
+                // the respawn button doubles as the ready button
+                for client in clients.values_mut() {
+                    client.ready = client.ready || client.pilot.respawn_is_pressed;
+                }
+
+                if !enough_players {
+                    self.enter(MatchPhase::Waiting, 0);
+                }
+                else if clients.values().all(|client| client.ready) {
+                    self.enter(MatchPhase::Countdown, rules.countdown_ms);
+                }
+            },
+            MatchPhase::Countdown => {
+                if !enough_players {
+                    self.enter(MatchPhase::Waiting, 0);
+                }
+                else if self.timer == 0 {
+                    start_round(clients, world, arena, bounds, settings);
+                    self.enter(MatchPhase::InProgress, rules.time_limit_ms);
+                }
+                else {
+                    self.timer -= 1;
+                }
+            },
+            MatchPhase::InProgress => {
+                let scores : Vec<(u8, u8, Score)> = clients.values().map(|client| (client.id, client.team, client.score.clone())).collect();
+                let time_is_up = rules.time_limit_ms > 0 && self.timer == 0;
+
+                let outcome = match settings.game_mode.outcome(&scores, world.wave, world.intermission.is_some(), settings.kill_limit, settings.coop_waves_to_win) {
+                    None if time_is_up => Some(settings.game_mode.leaders(&scores)),
+                    outcome            => outcome,
+                };
+
+                if clients.is_empty() {
+                    self.enter(MatchPhase::Waiting, 0);
+                }
+                else if let Some(outcome) = outcome {
+                    self.winners = match outcome {
+                        Outcome::Winners(winners) => { println!("Match won by {:?}", winners); winners },
+                        Outcome::Defeat           => { println!("Match lost"); Vec::new() },
+                    };
+                    self.enter(MatchPhase::Results, rules.results_ms);
+                }
+                else if self.timer > 0 {
+                    self.timer -= 1;
+                }
+            },
+            MatchPhase::Results => {
+                if self.timer == 0 {
+                    self.winners.clear();
+                    self.enter(MatchPhase::Waiting, 0);
+                }
+                else {
+                    self.timer -= 1;
+                }
+            },
+        }
+    }
 }
 
 pub fn run() {
@@ -26,14 +907,26 @@ pub fn run() {
     socket.bind(&server_address).unwrap();
 
     let settings = load_settings("settings.json");
+    let map = load_map(&settings.map);
+    let bounds = map.bounds();
+    println!("Game mode: {:?}", settings.game_mode);
 
     let mut accumulator = 0;
     let mut previous_clock = clock_ticks::precise_time_ns();
     let mut prev_message_sent = previous_clock;
+    let mut prev_scoreboard_sent = previous_clock;
+    let mut last_scoreboard : Vec<(u8, u8, Score)> = Vec::new();
+    let mut last_ready : Vec<bool> = Vec::new(); // alongside the scoreboard
+    let mut last_phase = MatchPhase::Waiting;
     let reader_options = ::capnp::message::ReaderOptions::new();
+    let mut rng = rand::thread_rng();
 
     let mut clients : HashMap<SocketAddr, Client> = HashMap::new();
 
+    let mut arena = Arena::new(&map);
+    let mut world = World::new(&settings);
+    let mut current_match = Match::new();
+
     loop {
         let now = clock_ticks::precise_time_ns();
         accumulator += now - previous_clock;
@@ -52,10 +945,20 @@ pub fn run() {
             let updated = {
                 match clients.get_mut(&from_address) {
                     Some(ref mut client) => {
-                        client.pilot.left_is_pressed  = message.get_turn_left();
-                        client.pilot.right_is_pressed = message.get_turn_right();
-                        client.pilot.up_is_pressed    = message.get_throttle();
-                        client.last_message           = now;
+                        client.pilot.left_is_pressed         = message.get_turn_left();
+                        client.pilot.right_is_pressed        = message.get_turn_right();
+                        client.pilot.up_is_pressed           = message.get_throttle();
+                        client.pilot.down_is_pressed         = message.get_reverse();
+                        client.pilot.brake_is_pressed        = message.get_brake();
+                        client.pilot.strafe_left_is_pressed  = message.get_strafe_left();
+                        client.pilot.strafe_right_is_pressed = message.get_strafe_right();
+                        client.pilot.fire_is_pressed         = message.get_fire();
+                        client.pilot.respawn_is_pressed      = message.get_respawn();
+                        client.pilot.hyperspace_is_pressed   = message.get_hyperspace();
+                        client.pilot.shield_is_pressed       = message.get_shield();
+                        client.pilot.mine_is_pressed         = message.get_drop_mine();
+                        client.pilot.missile_is_pressed      = message.get_fire_missile();
+                        client.last_message                  = now;
                         true
                     }
                     None => false
@@ -63,11 +966,24 @@ pub fn run() {
             };
 
             if !updated {
-                println!("New client from {:?}", from_address);
-                let mut pilot = Pilot::new(Integrator::ForwardEuler);
-                pilot.spawn().ok();
+                match free_client_id(&clients) {
+                    None     => println!("Turning away client from {:?}, all ids are taken", from_address),
+                    Some(id) => {
+                        println!("New client from {:?}", from_address);
+                        let mut pilot = Pilot::new(Integrator::ForwardEuler);
+                        pilot.class = (message.get_ship_class() as usize % settings.ship_classes.len()) as u8;
+                        pilot.hitbox = settings.ship_classes[pilot.class as usize].hitbox();
+                        pilot.mass = settings.ship_classes[pilot.class as usize].mass;
+                        pilot.hull_points = settings.ship_classes[pilot.class as usize].hull_points;
+                        pilot.spawn(pick_spawn_point(&clients, &world, &arena, &bounds, &settings), settings.spawn_invulnerability_ms).ok();
 
-                clients.insert(from_address, Client { last_message: now, pilot: pilot });
+                        let requested_team = if message.get_choose_team() { Some(message.get_team()) } else { None };
+                        let team = pick_team(&clients, requested_team, &settings);
+                        println!("Client {} joins team {}", id, team);
+
+                        clients.insert(from_address, Client { id: id, last_message: now, pilot: pilot, score: Score::new(settings.starting_lives), team: team, ready: false });
+                    },
+                }
             }
         }
 
@@ -75,39 +991,237 @@ pub fn run() {
         while accumulator >= FIXED_TIME_STAMP {
             accumulator -= FIXED_TIME_STAMP;
 
+            current_match.step(&mut clients, &mut world, &arena, &bounds, &settings);
+            if current_match.phase != MatchPhase::InProgress {
+                continue;
+            }
+
+            for well in arena.gravity_wells.iter_mut() {
+                well.step(&bounds);
+            }
+
+            let respawning : Vec<SocketAddr> = clients.iter()
+                .filter(|&(_, client)| client.pilot.respawn_is_pressed && client.pilot.can_respawn() && !client.score.is_game_over())
+                .map(|(address, _)| address.clone())
+                .collect();
+            for address in respawning {
+                let position = pick_spawn_point(&clients, &world, &arena, &bounds, &settings);
+                if let Some(client) = clients.get_mut(&address) {
+                    client.pilot.spawn(position, settings.spawn_invulnerability_ms).ok();
+                }
+            }
+
+            let ships : Vec<(u8, u8, Vec2<f32>)> = clients.values()
+                .filter_map(|client| client.pilot.ship.as_ref().map(|ship| (client.id, client.team, ship.position)))
+                .collect();
+
             for (_, client) in clients.iter_mut() {
+				let id = client.id;
+				let team = client.team;
+
+                if client.pilot.hyperspace_is_pressed && client.pilot.hyperspace_cooldown == 0 && client.pilot.ship.is_some() {
+                    client.pilot.hyperspace_cooldown = settings.hyperspace_cooldown_ms;
+
+                    if rng.gen::<f32>() < settings.hyperspace_failure_chance {
+                        println!("Client {} didn't make it out of hyperspace", id);
+                        client.lose_ship(&settings);
+                    }
+                    else if let Some(ref mut ship) = client.pilot.ship {
+                        ship.teleport(random_open_position(&arena, &bounds));
+                    }
+                }
+
 				let player = &mut client.pilot;
+
+                if player.fire_cooldown > 0 {
+                    player.fire_cooldown -= 1;
+                }
+                if player.respawn_timer > 0 {
+                    player.respawn_timer -= 1;
+                }
+                if player.hyperspace_cooldown > 0 {
+                    player.hyperspace_cooldown -= 1;
+                }
+                if player.rapid_fire_timer > 0 {
+                    player.rapid_fire_timer -= 1;
+                }
+                if player.spread_shot_timer > 0 {
+                    player.spread_shot_timer -= 1;
+                }
+                if player.mine_cooldown > 0 {
+                    player.mine_cooldown -= 1;
+                }
+                if player.missile_cooldown > 0 {
+                    player.missile_cooldown -= 1;
+                }
+
+                let class = &settings.ship_classes[player.class as usize];
+
                 match player.ship {
                     None => {}
                     Some(ref mut ship) => {
                         let prev_prev = ship.prev_position;
+                        let pull = gravity(&arena.gravity_wells, ship.position, &bounds);
+
+                        if ship.invulnerable > 0 {
+                            ship.invulnerable -= 1;
+                        }
 
                         ship.prev_position = ship.position;
                         ship.prev_rotation = ship.rotation;
 
-                        if player.left_is_pressed {
-                            ship.rotation += settings.rotation_speed;
-                        }
-                        if player.right_is_pressed {
-                            ship.rotation -= settings.rotation_speed;
+                        ship.update_shield(player.shield_is_pressed, settings.shield.drain_per_ms, settings.shield.recharge_per_ms);
+
+                        // with subsystem damage, a badly beaten up ship turns and thrusts sluggishly
+                        let performance = if settings.damage.subsystems && ship.integrity() < settings.damage.impaired_below {
+                            settings.damage.impaired_factor
                         }
-                        let acceleration = if player.up_is_pressed { settings.acceleration } else { 0f32 };
+                        else {
+                            1f32
+                        };
+
+                        let mut torque = 0f32;
+                        if player.left_is_pressed  { torque += class.angular_acceleration * performance; }
+                        if player.right_is_pressed { torque -= class.angular_acceleration * performance; }
+
+                        // turning has inertia of its own, the spin builds up and dies down
+                        // again instead of the angle changing at a fixed rate
+                        let spin = (ship.rotational_velocity + torque) * (1f32 - class.angular_drag);
+                        let max_spin = class.max_spin * performance;
+                        ship.rotational_velocity = spin.max(-max_spin).min(max_spin);
+                        ship.rotation += ship.rotational_velocity;
+
                         let direction = Vec2::new(f32::cos(ship.rotation), f32::sin(ship.rotation));
+                        let port = Vec2::new(-direction.y, direction.x); // to the left of the nose
+
+                        let mut thrust = Vec2::new(0f32, 0f32);
+                        if player.up_is_pressed           { thrust = thrust + direction * class.acceleration; }
+                        if player.down_is_pressed         { thrust = thrust - direction * class.reverse_acceleration; }
+                        if player.strafe_left_is_pressed  { thrust = thrust + port * class.strafe_acceleration; }
+                        if player.strafe_right_is_pressed { thrust = thrust - port * class.strafe_acceleration; }
+                        thrust = thrust * performance;
+
+                        // in fuel mode the thrusters burn it for as long as there is any left
+                        if settings.fuel.enabled && thrust != Vec2::new(0f32, 0f32) {
+                            if ship.fuel > 0f32 {
+                                ship.fuel = (ship.fuel - settings.fuel.burn_per_ms).max(0f32);
+                            }
+                            else {
+                                thrust = Vec2::new(0f32, 0f32);
+                            }
+                        }
+
+                        let drag = if player.brake_is_pressed { class.drag + class.brake_drag } else { class.drag };
 
                         match player.integrator {
                             Integrator::ForwardEuler => {
-                                ship.velocity = (ship.velocity + direction * acceleration) * (1f32 - settings.drag);
+                                ship.velocity = (ship.velocity + thrust + pull) * (1f32 - drag);
                                 ship.position = ship.position + ship.velocity;
                             },
                             Integrator::Verlet => {
                                 let instantaneous_velocity = ship.position - prev_prev;
-                                let drag = instantaneous_velocity * settings.drag;
-                                ship.position = ship.position + ship.position - prev_prev + (direction * acceleration) - drag;
+                                ship.position = ship.position + ship.position - prev_prev + thrust + pull - instantaneous_velocity * drag;
                             },
                         }
+
+                        ship.wrap(&bounds);
+
+                        let docked = arena.stations.iter().any(|station| {
+                            point_in_circle(bounds.nearest_image(station.position, ship.position), station.position, station.radius)
+                        });
+                        if settings.fuel.enabled && docked && norm(&ship.displacement()) <= settings.fuel.dock_speed {
+                            ship.fuel = (ship.fuel + settings.fuel.dock_refuel_per_ms).min(1f32);
+                        }
+
+                        if player.fire_is_pressed && player.fire_cooldown == 0 {
+                            let velocity = ship.position - ship.prev_position;
+                            let spread = settings.power_ups.spread_angle;
+                            let angles = if player.spread_shot_timer > 0 { vec![-spread, 0f32, spread] } else { vec![0f32] };
+
+                            for angle in angles {
+                                let direction = rotate(direction, angle);
+                                world.bullets.push(Bullet::new(Some(id),
+                                                               ship.position + direction * class.nose(),
+                                                               velocity + direction * class.weapon.bullet_speed,
+                                                               class.weapon.bullet_lifetime_ms));
+                            }
+                            player.fire_cooldown = if player.rapid_fire_timer > 0 {
+                                ::std::cmp::min(settings.power_ups.rapid_fire_cooldown_ms, class.weapon.cooldown_ms)
+                            }
+                            else {
+                                class.weapon.cooldown_ms
+                            };
+                        }
+
+                        if player.mine_is_pressed && player.mine_cooldown == 0 &&
+                           world.mines.iter().filter(|mine| mine.owner == id).count() < settings.mines.max_per_ship {
+                            world.mines.push(Mine::new(id, ship.position, settings.mines.arm_delay_ms, settings.mines.lifetime_ms));
+                            player.mine_cooldown = settings.mines.cooldown_ms;
+                        }
+
+                        if player.missile_is_pressed && player.missile_cooldown == 0 {
+                            let target = acquire_target(&ships, id, team, ship.position, ship.rotation, &bounds, &settings);
+                            world.missiles.push(Missile::new(id,
+                                                             target,
+                                                             ship.position + direction * class.nose(),
+                                                             ship.position - ship.prev_position,
+                                                             ship.rotation,
+                                                             settings.missiles.fuel_ms,
+                                                             settings.missiles.lifetime_ms));
+                            player.missile_cooldown = settings.missiles.cooldown_ms;
+                        }
                     }
                 }
             }
+
+            for asteroid in world.asteroids.iter_mut() {
+                asteroid.velocity = asteroid.velocity + gravity(&arena.gravity_wells, asteroid.position, &bounds);
+                asteroid.step(&bounds);
+            }
+
+            let targets : Vec<Vec2<f32>> = clients.values()
+                .filter_map(|client| client.pilot.ship.as_ref())
+                .map(|ship| ship.position)
+                .collect();
+            world.step_saucers(&targets, &bounds, &settings);
+
+            for bullet in world.bullets.iter_mut() {
+                bullet.velocity = bullet.velocity + gravity(&arena.gravity_wells, bullet.position, &bounds);
+                bullet.step(&bounds);
+            }
+            world.bullets.retain(|bullet| !bullet.is_expired());
+
+            for mine in world.mines.iter_mut() {
+                mine.step();
+            }
+            world.mines.retain(|mine| !mine.is_expired());
+
+            // a missile whose target is gone flies on straight ahead
+            let positions : HashMap<u8, Vec2<f32>> = clients.values()
+                .filter_map(|client| client.pilot.ship.as_ref().map(|ship| (client.id, ship.position)))
+                .collect();
+            for missile in world.missiles.iter_mut() {
+                let target = missile.target.and_then(|id| positions.get(&id).cloned());
+                if target.is_none() {
+                    missile.target = None;
+                }
+
+                let missiles = &settings.missiles;
+                missile.velocity = missile.velocity + gravity(&arena.gravity_wells, missile.position, &bounds);
+                missile.step(target, missiles.turn_rate, missiles.acceleration, missiles.drag, &bounds);
+            }
+            world.missiles.retain(|missile| !missile.is_expired());
+
+            world.step_power_ups(&bounds, &settings);
+            collect_power_ups(&mut clients, &mut world, &bounds, &settings);
+
+            resolve_collisions(&mut clients, &mut world, &arena, &bounds, &settings);
+
+            let ships : Vec<Vec2<f32>> = clients.values()
+                .filter_map(|client| client.pilot.ship.as_ref())
+                .map(|ship| ship.position)
+                .collect();
+            world.step_waves(&ships, &arena, &bounds, &settings);
         }
 
         if now - prev_message_sent >= settings.message_interval_ms * 1_000_000 {
@@ -123,30 +1237,227 @@ pub fn run() {
 			    }
 			}
 
-            let game_status_msg = {
+            let mut scoreboard : Vec<(u8, u8, Score)> = clients.values().map(|client| (client.id, client.team, client.score.clone())).collect();
+            scoreboard.sort_by(|a, b| a.0.cmp(&b.0));
+            let ready : Vec<bool> = scoreboard.iter().map(|&(id, _, _)| clients.values().any(|client| client.id == id && client.ready)).collect();
+            let send_scoreboard = scoreboard != last_scoreboard ||
+                                  ready != last_ready ||
+                                  current_match.phase != last_phase ||
+                                  now - prev_scoreboard_sent >= settings.scoreboard_interval_ms * 1_000_000;
+
+            let mut game_status_msg = {
                 let mut message = ::capnp::message::Builder::new_default();
                 {
                     let mut p = message.init_root::<game_status::Builder>();
                     p.set_timestamp(now);
+                    p.set_wave(world.wave as u16);
+                    p.set_phase(current_match.phase.to_u8());
+                    p.set_phase_time_left(current_match.timer as u32);
+                    p.set_fuel_limited(settings.fuel.enabled);
+
+                    {
+                        let num_ships = clients.values().filter(|client| client.pilot.ship().is_some()).count();
+                        let mut ships = p.borrow().init_ships(num_ships as u32);
+                        let mut count = 0;
+
+                        for client in clients.values()
+                        {
+                            if let Some(ref ship) = client.pilot.ship {
+                                let mut ship_msg = ships.borrow().get(count);
+                                let velocity = ship.position - ship.prev_position;
+                                ship_msg.set_id(client.id);
+                                ship_msg.set_team(client.team);
+                                ship_msg.set_x(ship.position.x);
+                                ship_msg.set_y(ship.position.y);
+                                ship_msg.set_dx(velocity.x);
+                                ship_msg.set_dy(velocity.y);
+                                ship_msg.set_ang(ship.rotation);
+                                ship_msg.set_dang(ship.rotational_velocity);
+                                ship_msg.set_invulnerable(ship.is_invulnerable());
+                                ship_msg.set_shield(ship.shield_active);
+                                ship_msg.set_energy(ship.energy);
+                                ship_msg.set_hull(ship.integrity());
+
+                                count += 1;
+                            }
+                        }
+                    }
+
+                    {
+                        let mut asteroids_msg = p.borrow().init_asteroids(world.asteroids.len() as u32);
 
-                    let num_ships = clients.values().filter(|client| client.pilot.ship().is_some()).count();
-                    let mut ships = p.borrow().init_ships(num_ships as u32);
-                    let mut count = 0;
+                        for (index, asteroid) in world.asteroids.iter().enumerate() {
+                            let mut asteroid_msg = asteroids_msg.borrow().get(index as u32);
+                            asteroid_msg.set_id(asteroid.id);
+                            asteroid_msg.set_size(asteroid.size);
+                            asteroid_msg.set_radius(asteroid.radius);
+                            asteroid_msg.set_x(asteroid.position.x);
+                            asteroid_msg.set_y(asteroid.position.y);
+                            asteroid_msg.set_dx(asteroid.velocity.x);
+                            asteroid_msg.set_dy(asteroid.velocity.y);
+                            asteroid_msg.set_ang(asteroid.rotation);
+                            asteroid_msg.set_dang(asteroid.rotational_velocity);
+                        }
+                    }
 
-                    for client in clients.values()
                     {
-                        if let Some(ref ship) = client.pilot.ship {
-                            let mut ship_msg = ships.borrow().get(count);
-                            let velocity = ship.position - ship.prev_position;
-                            ship_msg.set_id(0);
-                            ship_msg.set_x(ship.position.x);
-                            ship_msg.set_y(ship.position.y);
-                            ship_msg.set_dx(velocity.x);
-                            ship_msg.set_dy(velocity.y);
-                            ship_msg.set_ang(ship.rotation);
-                            ship_msg.set_dang(ship.rotation - ship.prev_rotation);
+                        let mut bullets_msg = p.borrow().init_bullets(world.bullets.len() as u32);
+
+                        for (index, bullet) in world.bullets.iter().enumerate() {
+                            let mut bullet_msg = bullets_msg.borrow().get(index as u32);
+                            bullet_msg.set_owner(bullet.owner.unwrap_or(0));
+                            bullet_msg.set_from_saucer(bullet.owner.is_none());
+                            bullet_msg.set_x(bullet.position.x);
+                            bullet_msg.set_y(bullet.position.y);
+                            bullet_msg.set_dx(bullet.velocity.x);
+                            bullet_msg.set_dy(bullet.velocity.y);
+                        }
+                    }
+
+                    {
+                        let mut saucers_msg = p.borrow().init_saucers(world.saucers.len() as u32);
+
+                        for (index, saucer) in world.saucers.iter().enumerate() {
+                            let mut saucer_msg = saucers_msg.borrow().get(index as u32);
+                            saucer_msg.set_id(saucer.id);
+                            saucer_msg.set_small(saucer.small);
+                            saucer_msg.set_x(saucer.position.x);
+                            saucer_msg.set_y(saucer.position.y);
+                            saucer_msg.set_dx(saucer.velocity.x);
+                            saucer_msg.set_dy(saucer.velocity.y);
+                        }
+                    }
+
+                    {
+                        let mut power_ups_msg = p.borrow().init_power_ups(world.power_ups.len() as u32);
+
+                        for (index, power_up) in world.power_ups.iter().enumerate() {
+                            let mut power_up_msg = power_ups_msg.borrow().get(index as u32);
+                            power_up_msg.set_id(power_up.id);
+                            power_up_msg.set_kind(power_up.kind);
+                            power_up_msg.set_x(power_up.position.x);
+                            power_up_msg.set_y(power_up.position.y);
+                        }
+                    }
+
+                    {
+                        let mut mines_msg = p.borrow().init_mines(world.mines.len() as u32);
+
+                        for (index, mine) in world.mines.iter().enumerate() {
+                            let mut mine_msg = mines_msg.borrow().get(index as u32);
+                            mine_msg.set_owner(mine.owner);
+                            mine_msg.set_x(mine.position.x);
+                            mine_msg.set_y(mine.position.y);
+                            mine_msg.set_armed(mine.is_armed());
+                        }
+                    }
+
+                    {
+                        let mut events_msg = p.borrow().init_damage_events(world.damage_events.len() as u32);
+
+                        for (index, &(id, amount)) in world.damage_events.iter().enumerate() {
+                            let mut event_msg = events_msg.borrow().get(index as u32);
+                            event_msg.set_ship(id);
+                            event_msg.set_amount(amount);
+                        }
+                    }
 
-                            count += 1;
+                    {
+                        let mut missiles_msg = p.borrow().init_missiles(world.missiles.len() as u32);
+
+                        for (index, missile) in world.missiles.iter().enumerate() {
+                            let mut missile_msg = missiles_msg.borrow().get(index as u32);
+                            missile_msg.set_owner(missile.owner);
+                            missile_msg.set_target(missile.target.unwrap_or(0));
+                            missile_msg.set_locked(missile.target.is_some());
+                            missile_msg.set_x(missile.position.x);
+                            missile_msg.set_y(missile.position.y);
+                            missile_msg.set_dx(missile.velocity.x);
+                            missile_msg.set_dy(missile.velocity.y);
+                            missile_msg.set_ang(missile.rotation);
+                        }
+                    }
+
+                    {
+                        let mut wells_msg = p.borrow().init_gravity_wells(arena.gravity_wells.len() as u32);
+
+                        for (index, well) in arena.gravity_wells.iter().enumerate() {
+                            let mut well_msg = wells_msg.borrow().get(index as u32);
+                            well_msg.set_x(well.position.x);
+                            well_msg.set_y(well.position.y);
+                            well_msg.set_dx(well.velocity.x);
+                            well_msg.set_dy(well.velocity.y);
+                            well_msg.set_radius(well.radius);
+                        }
+                    }
+
+                    // like the map, the ship infos only go out with the scoreboard
+                    if send_scoreboard {
+                        let mut infos_msg = p.borrow().init_ship_infos(clients.len() as u32);
+
+                        for (index, client) in clients.values().enumerate() {
+                            let class = &settings.ship_classes[client.pilot.class as usize];
+                            let color = team_color(client.team);
+                            let mut info_msg = infos_msg.borrow().get(index as u32);
+                            info_msg.set_id(client.id);
+                            info_msg.set_r((color[0] * 255f32) as u8);
+                            info_msg.set_g((color[1] * 255f32) as u8);
+                            info_msg.set_b((color[2] * 255f32) as u8);
+                            info_msg.set_ship_class(client.pilot.class);
+                            info_msg.set_class_name(&class.name);
+                        }
+                    }
+
+                    // the map goes out with the scoreboard, so new clients get it right after joining
+                    if send_scoreboard {
+                        let mut map_msg = p.borrow().init_map();
+                        map_msg.set_width(map.width);
+                        map_msg.set_height(map.height);
+                        map_msg.set_walled(map.walled);
+
+                        {
+                            let mut obstacles_msg = map_msg.borrow().init_obstacles(map.obstacles.len() as u32);
+                            for (index, obstacle) in map.obstacles.iter().enumerate() {
+                                let mut points_msg = obstacles_msg.borrow().get(index as u32).init_points(obstacle.len() as u32);
+                                for (point_index, point) in obstacle.iter().enumerate() {
+                                    let mut point_msg = points_msg.borrow().get(point_index as u32);
+                                    point_msg.set_x(point.x);
+                                    point_msg.set_y(point.y);
+                                }
+                            }
+                        }
+
+                        {
+                            let mut spawn_points_msg = map_msg.borrow().init_spawn_points(map.spawn_points.len() as u32);
+                            for (index, point) in map.spawn_points.iter().enumerate() {
+                                let mut point_msg = spawn_points_msg.borrow().get(index as u32);
+                                point_msg.set_x(point.x);
+                                point_msg.set_y(point.y);
+                            }
+                        }
+
+                        let mut stations_msg = map_msg.borrow().init_stations(map.stations.len() as u32);
+                        for (index, station) in map.stations.iter().enumerate() {
+                            let mut station_msg = stations_msg.borrow().get(index as u32);
+                            station_msg.set_x(station.x);
+                            station_msg.set_y(station.y);
+                            station_msg.set_radius(station.radius);
+                        }
+                    }
+
+                    if send_scoreboard {
+                        let mut scoreboard_msg = p.borrow().init_scoreboard(scoreboard.len() as u32);
+
+                        for (index, &(id, team, ref score)) in scoreboard.iter().enumerate() {
+                            let mut entry_msg = scoreboard_msg.borrow().get(index as u32);
+                            entry_msg.set_id(id);
+                            entry_msg.set_team(team);
+                            entry_msg.set_points(score.points);
+                            entry_msg.set_kills(::std::cmp::min(score.kills, ::std::u16::MAX as u32) as u16);
+                            entry_msg.set_deaths(::std::cmp::min(score.deaths, ::std::u16::MAX as u32) as u16);
+                            entry_msg.set_lives(::std::cmp::min(score.lives, ::std::u8::MAX as u32) as u8);
+                            entry_msg.set_winner(current_match.winners.contains(&id));
+                            entry_msg.set_ready(ready[index]);
                         }
                     }
                 }
@@ -154,7 +1465,22 @@ pub fn run() {
                 message
             };
 
-            for address in clients.keys() {
+            world.damage_events.clear();
+
+            if send_scoreboard {
+                last_scoreboard = scoreboard;
+                last_ready = ready;
+                last_phase = current_match.phase;
+                prev_scoreboard_sent = now;
+            }
+
+            for (address, client) in clients.iter() {
+                {
+                    let mut p = game_status_msg.get_root::<game_status::Builder>().unwrap();
+                    p.set_player_id(client.id);
+                    p.set_fuel(client.pilot.ship.as_ref().map_or(0f32, |ship| ship.fuel));
+                }
+
                 let mut buffer = Vec::new();
                 serialize_packed::write_message(&mut buffer, &game_status_msg).unwrap();
                 let result = socket.send_to(&mut SliceBuf::wrap(&buffer), &address);
@@ -165,3 +1491,21 @@ pub fn run() {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::asteroid_points;
+
+    #[test]
+    fn asteroid_points_by_size() {
+        assert_eq!(asteroid_points(&[100, 50, 20], 1), 100);
+        assert_eq!(asteroid_points(&[100, 50, 20], 3), 20);
+    }
+
+    #[test]
+    fn asteroid_points_for_sizes_without_points() {
+        assert_eq!(asteroid_points(&[100, 50, 20], 0), 0);
+        assert_eq!(asteroid_points(&[100, 50, 20], 4), 0);
+        assert_eq!(asteroid_points(&[], 1), 0);
+    }
+}
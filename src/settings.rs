@@ -2,16 +2,220 @@ extern crate rustc_serialize;
 use std::io::prelude::*;
 use std::fs::File;
 use self::rustc_serialize::*;
+use nalgebra::Vec2;
+use game_mode::GameMode;
+use collision::is_convex;
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct WeaponSettings {
+    pub cooldown_ms:        u64,
+    pub bullet_speed:       f32,
+    pub bullet_lifetime_ms: u64,
+}
+
+// handling, looks and armament of one kind of ship; outlines are in ship-local
+// coordinates, pointing along the x axis
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct ShipClassSettings {
+    pub name:                 String,
+    pub angular_acceleration: f32,
+    pub angular_drag:         f32,
+    pub max_spin:             f32, // radians per ms, whichever way
+    pub drag:                 f32,
+    pub acceleration:         f32,
+    pub reverse_acceleration: f32,
+    pub strafe_acceleration:  f32, // sideways, in either direction
+    pub brake_drag:           f32, // added to the drag while braking
+    pub mass:                 f32,
+    pub hull_points:          f32,
+    pub hull:                 Vec<Point>, // what gets drawn, star shaped around the origin
+    pub hitbox:               Vec<Point>, // what collides, must be convex
+    pub weapon:               WeaponSettings,
+}
+
+impl ShipClassSettings {
+    pub fn hull(&self) -> Vec<Vec2<f32>> {
+        self.hull.iter().map(|point| Vec2::new(point.x, point.y)).collect()
+    }
+
+    pub fn hitbox(&self) -> Vec<Vec2<f32>> {
+        self.hitbox.iter().map(|point| Vec2::new(point.x, point.y)).collect()
+    }
+
+    // where bullets come out
+    pub fn nose(&self) -> f32 {
+        self.hitbox.iter().fold(0f32, |nose, point| nose.max(point.x))
+    }
+
+    // furthest any part of the hull reaches from the ship's center
+    pub fn radius(&self) -> f32 {
+        self.hull.iter().fold(0f32, |radius, point| radius.max((point.x * point.x + point.y * point.y).sqrt()))
+    }
+}
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct GravityWellSettings {
+    pub x:               f32,
+    pub y:               f32,
+    pub radius:          f32,
+    pub strength:        f32, // acceleration at a distance of 1
+    pub orbit_radius:    f32, // around x, y; 0 for a well that stays put
+    pub orbit_period_ms: u64,
+}
+
+// ships inside one that are nearly standing still get their tank filled up
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct StationSettings {
+    pub x:      f32,
+    pub y:      f32,
+    pub radius: f32,
+}
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct WaveSettings {
+    pub first_wave_asteroids: u32,
+    pub asteroids_per_wave:   u32, // added on top of the previous wave
+    pub max_asteroids:        u32,
+    pub first_wave_speed:     f32,
+    pub speed_per_wave:       f32,
+    pub intermission_ms:      u64, // pause between clearing a wave and the next one
+}
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct SaucerSettings {
+    pub spawn_interval_ms:   u64,
+    pub speed:               f32,
+    pub large_radius:        f32,
+    pub small_radius:        f32,
+    pub small_from_wave:     u32, // before this wave only large saucers show up
+    pub course_change_ms:    u64,
+    pub fire_cooldown_ms:    u64,
+    pub bullet_speed:        f32,
+    pub inaccuracy:          f32, // largest aiming error in radians, on the first wave
+    pub inaccuracy_per_wave: f32,
+    pub min_inaccuracy:      f32,
+    pub large_points:        u32,
+    pub small_points:        u32,
+}
+
+// shield energy goes from 0 to 1
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct ShieldSettings {
+    pub drain_per_ms:    f32,
+    pub recharge_per_ms: f32,
+}
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct PowerUpSettings {
+    pub spawn_interval_min_ms:  u64,
+    pub spawn_interval_max_ms:  u64,
+    pub max_power_ups:          usize,
+    pub lifetime_ms:            u64, // how long one stays in the arena if nobody picks it up
+    pub rapid_fire_ms:          u64,
+    pub rapid_fire_cooldown_ms: u64,
+    pub spread_shot_ms:         u64,
+    pub spread_angle:           f32, // between the middle and each of the side bullets
+}
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct MineSettings {
+    pub cooldown_ms:  u64,
+    pub arm_delay_ms: u64, // before this a mine can't go off, so it doesn't get its owner
+    pub lifetime_ms:  u64,
+    pub radius:       f32, // both for setting it off and for the blast
+    pub max_per_ship: usize,
+}
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct MissileSettings {
+    pub cooldown_ms:  u64,
+    pub acceleration: f32,
+    pub drag:         f32,
+    pub turn_rate:    f32, // radians per ms
+    pub fuel_ms:      u64, // how long it keeps steering and thrusting
+    pub lifetime_ms:  u64,
+    pub lock_cone:    f32, // largest angle off the nose a target can be locked on at
+    pub lock_range:   f32,
+}
+
+// ships bounce off each other and off asteroids instead of passing through
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct CollisionSettings {
+    pub restitution:          f32, // 1 for a perfectly elastic bounce, 0 for none at all
+    pub asteroid_density:     f32, // mass per radius squared, ships have theirs in the class
+    pub impact_damage:        f32, // hull points per relative speed times the other body's mass, 0 for no damage
+    pub asteroid_break_speed: f32, // an asteroid hit at least this hard breaks up
+}
+
+// the tank goes from 0 to 1, like shield energy
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct FuelSettings {
+    pub enabled:            bool, // without it thrust is unlimited
+    pub burn_per_ms:        f32,
+    pub pickup_amount:      f32,
+    pub dock_refuel_per_ms: f32,
+    pub dock_speed:         f32, // fastest a ship can be going and still dock
+}
+
+// amounts are in hull points, of which every ship class has its own number
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct DamageSettings {
+    pub bullet:          f32,
+    pub missile:         f32,
+    pub mine:            f32,
+    pub ram:             f32,  // for flying into a saucer
+    pub hazard_per_ms:   f32,  // while touching a gravity well or an obstacle
+    pub subsystems:      bool, // whether heavy damage makes a ship turn and thrust sluggishly
+    pub impaired_below:  f32,  // fraction of the hull left
+    pub impaired_factor: f32,
+}
+
+#[derive(RustcDecodable, RustcEncodable)]
+pub struct MatchSettings {
+    pub min_players:   usize,
+    pub countdown_ms:  u64,
+    pub time_limit_ms: u64, // 0 for no limit
+    pub results_ms:    u64, // how long the results are shown before the next match
+}
 
 #[derive(RustcDecodable, RustcEncodable)]
 pub struct Settings  {
-    pub rotation_speed:      f32,
-    pub drag:                f32,
-    pub acceleration:        f32,
-    pub print_fps:           bool,
-    pub fullscreen:          bool,
-    pub message_interval_ms: u64,
-    pub client_timeout_ms:   u64,
+    pub print_fps:                 bool,
+    pub fullscreen:                bool,
+    pub message_interval_ms:       u64,
+    pub client_timeout_ms:         u64,
+    pub asteroid_spin:             f32,
+    pub bullet_lifetime_ms:        u64,
+    pub map:                       String, // path to the arena the server loads
+    pub respawn_delay_ms:          u64,
+    pub spawn_invulnerability_ms:  u64,
+    pub spawn_candidates:          u32, // random points tried on maps without spawn points, at least 1
+    pub starting_lives:            u32,
+    pub asteroid_points:           Vec<u32>,   // indexed by asteroid size, smallest first
+    pub ship_points:               u32,
+    pub scoreboard_interval_ms:    u64,
+    pub hyperspace_cooldown_ms:    u64,
+    pub hyperspace_failure_chance: f32, // chance of being destroyed on every jump
+    pub game_mode:                 GameMode,
+    pub kill_limit:                u32, // kills needed to win a deathmatch, 0 for no limit
+    pub coop_waves_to_win:         u32,
+    pub match_rules:               MatchSettings,
+    pub ship_classes:              Vec<ShipClassSettings>, // the first one is the default
+    pub waves:                     WaveSettings,
+    pub shield:                    ShieldSettings,
+    pub power_ups:                 PowerUpSettings,
+    pub saucers:                   SaucerSettings,
+    pub mines:                     MineSettings,
+    pub missiles:                  MissileSettings,
+    pub collisions:                CollisionSettings,
+    pub fuel:                      FuelSettings,
+    pub damage:                    DamageSettings,
 }
 
 pub fn load_settings(filename: &str) -> Settings {
@@ -21,5 +225,35 @@ pub fn load_settings(filename: &str) -> Settings {
 
     let decoded: Settings = json::decode(&s).unwrap();
 
+    if decoded.ship_classes.is_empty() {
+        panic!("There needs to be at least one ship class");
+    }
+    if decoded.spawn_candidates < 1 {
+        panic!("spawn_candidates needs to be at least 1");
+    }
+    if decoded.power_ups.spawn_interval_min_ms > decoded.power_ups.spawn_interval_max_ms {
+        panic!("power_ups.spawn_interval_min_ms can't be more than spawn_interval_max_ms");
+    }
+    if decoded.collisions.asteroid_density <= 0f32 {
+        panic!("collisions.asteroid_density needs to be above 0");
+    }
+    if decoded.collisions.restitution < 0f32 || decoded.collisions.restitution > 1f32 {
+        panic!("collisions.restitution needs to be between 0 and 1");
+    }
+    for class in decoded.ship_classes.iter() {
+        if class.hull_points <= 0f32 {
+            panic!("Ship class {} needs more than 0 hull points", class.name);
+        }
+        if class.mass <= 0f32 {
+            panic!("Ship class {} needs a mass above 0", class.name);
+        }
+        if class.hitbox.len() < 3 {
+            panic!("Ship class {} needs at least 3 points in its hitbox", class.name);
+        }
+        if !is_convex(&class.hitbox()) {
+            panic!("Ship class {} needs a convex hitbox", class.name);
+        }
+    }
+
     decoded
 }